@@ -0,0 +1,210 @@
+//! Packaged `.pkg` asset bundles: a genuine `tar` directory index (entry name, size, and
+//! 512-byte padding) whose body is an 8-byte big-endian uncompressed length followed by
+//! `lz4`-compressed bytes. `AssetPack::open` reads the whole file once and walks the tar headers
+//! into an in-memory index of `logical_path -> (offset, compressed_len, uncompressed_len)`
+//! without decompressing anything; `fetch` only touches, and only decompresses, the one entry
+//! actually requested. `AssetServer` mounts packs ahead of its loose-file `roots` so a shipped
+//! game can read a single file instead of opening assets one at a time.
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+const BLOCK_SIZE: usize = 512;
+const NAME_OFFSET: usize = 0;
+const NAME_LEN: usize = 100;
+const SIZE_OFFSET: usize = 124;
+const SIZE_LEN: usize = 12;
+const LENGTH_PREFIX_SIZE: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PackEntry {
+    offset: usize,
+    compressed_len: usize,
+    uncompressed_len: usize,
+}
+
+#[derive(Debug)]
+pub enum AssetPackError {
+    Io(std::io::Error),
+    Truncated,
+    Decompress(lz4_flex::block::DecompressError),
+}
+
+impl Display for AssetPackError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AssetPackError::Io(e) => write!(f, "I/O error: {e}"),
+            AssetPackError::Truncated => write!(f, "truncated or corrupt pack archive"),
+            AssetPackError::Decompress(e) => write!(f, "lz4 decompress error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for AssetPackError {}
+
+impl From<std::io::Error> for AssetPackError {
+    fn from(value: std::io::Error) -> Self {
+        AssetPackError::Io(value)
+    }
+}
+
+/// A loaded `.pkg` archive: the raw file bytes plus an index of where each entry's compressed
+/// body lives within them. Cheap to mount (one read + header walk), since nothing is decompressed
+/// until `fetch` is called for a specific logical path.
+pub struct AssetPack {
+    data: Vec<u8>,
+    index: HashMap<String, PackEntry>,
+}
+
+impl AssetPack {
+    pub fn open(path: &Path) -> Result<Self, AssetPackError> {
+        let data = fs::read(path)?;
+        let index = build_index(&data)?;
+        Ok(Self { data, index })
+    }
+
+    pub fn contains(&self, logical_path: &str) -> bool {
+        self.index.contains_key(logical_path)
+    }
+
+    /// Decompresses the entry for `logical_path`. Returns `None` if this pack doesn't have the
+    /// entry at all, so callers can fall through to the next pack or a loose file; returns
+    /// `Some(Err(_))` if the entry exists but its compressed bytes are corrupt.
+    pub fn fetch(&self, logical_path: &str) -> Option<Result<Vec<u8>, AssetPackError>> {
+        let entry = self.index.get(logical_path)?;
+        let compressed = &self.data[entry.offset..entry.offset + entry.compressed_len];
+        Some(
+            lz4_flex::block::decompress(compressed, entry.uncompressed_len)
+                .map_err(AssetPackError::Decompress),
+        )
+    }
+
+    pub fn entry_count(&self) -> usize {
+        self.index.len()
+    }
+}
+
+fn build_index(data: &[u8]) -> Result<HashMap<String, PackEntry>, AssetPackError> {
+    let mut index = HashMap::new();
+    let mut cursor = 0usize;
+
+    while cursor + BLOCK_SIZE <= data.len() {
+        let header = &data[cursor..cursor + BLOCK_SIZE];
+        if header.iter().all(|&b| b == 0) {
+            break;
+        }
+
+        let name = parse_cstr(&header[NAME_OFFSET..NAME_OFFSET + NAME_LEN]);
+        let stored_len = parse_octal(&header[SIZE_OFFSET..SIZE_OFFSET + SIZE_LEN])?;
+        let body_start = cursor + BLOCK_SIZE;
+        if stored_len < LENGTH_PREFIX_SIZE || body_start + stored_len > data.len() {
+            return Err(AssetPackError::Truncated);
+        }
+
+        let prefix = &data[body_start..body_start + LENGTH_PREFIX_SIZE];
+        let uncompressed_len = u64::from_be_bytes(prefix.try_into().unwrap()) as usize;
+        let compressed_len = stored_len - LENGTH_PREFIX_SIZE;
+        let offset = body_start + LENGTH_PREFIX_SIZE;
+
+        if !name.is_empty() {
+            index.insert(
+                name,
+                PackEntry {
+                    offset,
+                    compressed_len,
+                    uncompressed_len,
+                },
+            );
+        }
+
+        let padded_len = (stored_len + BLOCK_SIZE - 1) / BLOCK_SIZE * BLOCK_SIZE;
+        if body_start + padded_len > data.len() {
+            return Err(AssetPackError::Truncated);
+        }
+        cursor = body_start + padded_len;
+    }
+
+    Ok(index)
+}
+
+fn parse_cstr(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    String::from_utf8_lossy(&bytes[..end]).into_owned()
+}
+
+fn parse_octal(bytes: &[u8]) -> Result<usize, AssetPackError> {
+    let text = parse_cstr(bytes);
+    let text = text.trim();
+    if text.is_empty() {
+        return Ok(0);
+    }
+    usize::from_str_radix(text, 8).map_err(|_| AssetPackError::Truncated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tar_header(name: &str, size: usize) -> [u8; BLOCK_SIZE] {
+        let mut header = [0u8; BLOCK_SIZE];
+        header[NAME_OFFSET..NAME_OFFSET + name.len()].copy_from_slice(name.as_bytes());
+        let size_octal = format!("{:011o}\0", size);
+        header[SIZE_OFFSET..SIZE_OFFSET + size_octal.len()].copy_from_slice(size_octal.as_bytes());
+        header
+    }
+
+    fn append_entry(data: &mut Vec<u8>, name: &str, uncompressed: &[u8]) {
+        let compressed = lz4_flex::block::compress(uncompressed);
+        let body_len = LENGTH_PREFIX_SIZE + compressed.len();
+        data.extend_from_slice(&tar_header(name, body_len));
+        data.extend_from_slice(&(uncompressed.len() as u64).to_be_bytes());
+        data.extend_from_slice(&compressed);
+        let padding = (BLOCK_SIZE - (body_len % BLOCK_SIZE)) % BLOCK_SIZE;
+        data.extend(std::iter::repeat(0u8).take(padding));
+    }
+
+    fn build_pack(entries: &[(&str, &[u8])]) -> AssetPack {
+        let mut data = Vec::new();
+        for (name, contents) in entries {
+            append_entry(&mut data, name, contents);
+        }
+        data.extend(std::iter::repeat(0u8).take(BLOCK_SIZE * 2));
+        AssetPack {
+            data: data.clone(),
+            index: build_index(&data).unwrap(),
+        }
+    }
+
+    #[test]
+    fn fetch_returns_original_bytes() {
+        let pack = build_pack(&[("assets/player.png", b"hello world this is pixel data")]);
+        assert_eq!(pack.entry_count(), 1);
+        assert!(pack.contains("assets/player.png"));
+
+        let fetched = pack.fetch("assets/player.png").unwrap().unwrap();
+        assert_eq!(fetched, b"hello world this is pixel data");
+    }
+
+    #[test]
+    fn fetch_missing_entry_returns_none() {
+        let pack = build_pack(&[("assets/player.png", b"data")]);
+        assert!(pack.fetch("assets/missing.png").is_none());
+    }
+
+    #[test]
+    fn multiple_entries_resolve_independently() {
+        let pack = build_pack(&[("assets/a.png", b"aaaa"), ("assets/b.png", b"bbbbbbbb")]);
+        assert_eq!(pack.fetch("assets/a.png").unwrap().unwrap(), b"aaaa");
+        assert_eq!(pack.fetch("assets/b.png").unwrap().unwrap(), b"bbbbbbbb");
+    }
+
+    #[test]
+    fn truncated_archive_is_rejected() {
+        let mut data = Vec::new();
+        append_entry(&mut data, "assets/a.png", b"aaaa");
+        data.truncate(data.len() - 4);
+        assert!(matches!(build_index(&data), Err(AssetPackError::Truncated)));
+    }
+}
@@ -0,0 +1,124 @@
+//! Shelf/skyline texture atlas packer: packs many small sprite textures into fixed-size
+//! (`ATLAS_SIZE` square) layers, spilling into a new layer once one fills up, and hands back a
+//! normalized UV rect per packed texture for `RenderableObject::uv_min`/`uv_max`.
+
+use std::collections::HashMap;
+
+/// Fixed atlas layer dimension; a layer is always a square of this size, and packing spills into
+/// a new layer rather than growing an existing one.
+pub const ATLAS_SIZE: u32 = 2048;
+
+/// Sub-texture size `Game::build_renderables` packs every texture at. `AssetServer` doesn't track
+/// a loaded texture's real pixel dimensions, so there's no per-texture `w`/`h` to pack with yet;
+/// until that metadata exists, every texture reserves one fixed-size square, which is enough to
+/// exercise real atlas/UV/layer assignment end to end without inventing texture-size tracking.
+pub const DEFAULT_SPRITE_TILE: u32 = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AtlasRect {
+    pub layer: u32,
+    pub u0: f32,
+    pub v0: f32,
+    pub u1: f32,
+    pub v1: f32,
+}
+
+/// One horizontal strip of a layer: everything placed on it shares `height`, growing rightward
+/// from `current_x` until it no longer fits, at which point a new shelf opens above it.
+struct Shelf {
+    baseline_y: u32,
+    current_x: u32,
+    height: u32,
+}
+
+struct Layer {
+    shelves: Vec<Shelf>,
+    /// Top of the highest shelf placed so far; where the next new shelf would start.
+    stack_y: u32,
+}
+
+impl Layer {
+    fn new() -> Self {
+        Self { shelves: Vec::new(), stack_y: 0 }
+    }
+
+    /// First-fit: scans existing shelves for one tall enough and with enough remaining width,
+    /// else opens a new shelf at the current stack height. Returns `None` if `w`/`h` can't fit
+    /// anywhere in this layer (including a fresh shelf), meaning the caller should spill to the
+    /// next layer.
+    fn place(&mut self, w: u32, h: u32) -> Option<(u32, u32)> {
+        for shelf in &mut self.shelves {
+            if shelf.height >= h && ATLAS_SIZE.saturating_sub(shelf.current_x) >= w {
+                let x = shelf.current_x;
+                shelf.current_x += w;
+                return Some((x, shelf.baseline_y));
+            }
+        }
+
+        if w > ATLAS_SIZE || ATLAS_SIZE.saturating_sub(self.stack_y) < h {
+            return None;
+        }
+
+        let baseline_y = self.stack_y;
+        self.stack_y += h;
+        self.shelves.push(Shelf { baseline_y, current_x: w, height: h });
+        Some((0, baseline_y))
+    }
+}
+
+/// Packs `w x h` sub-textures, keyed by an arbitrary `u32` id, into one or more `ATLAS_SIZE`
+/// layers. Re-packing an id already placed returns its existing rect instead of allocating again.
+pub struct AtlasPacker {
+    layers: Vec<Layer>,
+    placements: HashMap<u32, AtlasRect>,
+}
+
+impl AtlasPacker {
+    pub fn new() -> Self {
+        Self {
+            layers: vec![Layer::new()],
+            placements: HashMap::new(),
+        }
+    }
+
+    /// Places `id`'s `w x h` sub-texture on the newest layer, opening a new layer if it doesn't
+    /// fit there. Earlier layers are not revisited, even if they still have room.
+    pub fn pack(&mut self, id: u32, w: u32, h: u32) -> AtlasRect {
+        if let Some(rect) = self.placements.get(&id) {
+            return *rect;
+        }
+
+        let (layer_idx, x, y) = loop {
+            let last = self.layers.len() - 1;
+            if let Some((x, y)) = self.layers[last].place(w, h) {
+                break (last, x, y);
+            }
+            self.layers.push(Layer::new());
+        };
+
+        let scale = 1.0 / ATLAS_SIZE as f32;
+        let rect = AtlasRect {
+            layer: layer_idx as u32,
+            u0: x as f32 * scale,
+            v0: y as f32 * scale,
+            u1: (x + w) as f32 * scale,
+            v1: (y + h) as f32 * scale,
+        };
+        self.placements.insert(id, rect);
+        rect
+    }
+
+    pub fn layer_count(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn rects(&self) -> &HashMap<u32, AtlasRect> {
+        &self.placements
+    }
+}
+
+impl Default for AtlasPacker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
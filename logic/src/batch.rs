@@ -0,0 +1,52 @@
+//! Draw-call batching: `build_renderables`/`build_renderables_from_world` produce one
+//! `RenderableObject` per entity, which would otherwise cost one draw call each. `batch_renderables`
+//! groups them by `(mesh_id, material_id, texture_id)` so the host can issue one instanced draw per
+//! group instead.
+
+use crate::ffi;
+
+/// Stable-sorts `renderables` by `(mesh_id, material_id, texture_id)`, then emits one `DrawBatch`
+/// per contiguous run of that key alongside a matching buffer of per-instance transforms the host
+/// can upload and index with `instance_start..instance_start + instance_count`.
+pub fn batch_renderables(
+    renderables: &[ffi::RenderableObject],
+) -> (Vec<ffi::DrawBatch>, Vec<ffi::Transform>) {
+    let mut order: Vec<usize> = (0..renderables.len()).collect();
+    order.sort_by_key(|&i| {
+        let r = &renderables[i];
+        (r.mesh_id, r.material_id, r.texture_id)
+    });
+
+    let mut batches = Vec::new();
+    let mut instances = Vec::with_capacity(renderables.len());
+
+    let mut i = 0;
+    while i < order.len() {
+        let key_renderable = &renderables[order[i]];
+        let (mesh_id, material_id, texture_id) = (
+            key_renderable.mesh_id,
+            key_renderable.material_id,
+            key_renderable.texture_id,
+        );
+        let instance_start = instances.len() as u32;
+
+        while i < order.len() {
+            let r = &renderables[order[i]];
+            if (r.mesh_id, r.material_id, r.texture_id) != (mesh_id, material_id, texture_id) {
+                break;
+            }
+            instances.push(r.transform);
+            i += 1;
+        }
+
+        batches.push(ffi::DrawBatch {
+            mesh_id,
+            material_id,
+            texture_id,
+            instance_start,
+            instance_count: instances.len() as u32 - instance_start,
+        });
+    }
+
+    (batches, instances)
+}
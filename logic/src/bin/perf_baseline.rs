@@ -1,8 +1,15 @@
-use miyabi_logic::perf::{run_performance_baseline, PerfConfig};
+use miyabi_logic::perf::{
+    run_performance_baseline_with_progress, BenchmarkPhase, PerfConfig, PerfReport, ProgressEvent,
+};
+use serde::Deserialize;
 use std::env;
 use std::error::Error;
 use std::fs;
+use std::io::{self, IsTerminal, Write};
 use std::path::PathBuf;
+use std::process::ExitCode;
+
+const DEFAULT_FAIL_THRESHOLD_PCT: f64 = 10.0;
 
 fn parse_u32_arg(flag: &str, value: Option<String>) -> Result<u32, Box<dyn Error>> {
     let value = value.ok_or_else(|| format!("{flag} には数値が必要です"))?;
@@ -14,40 +21,191 @@ fn parse_usize_arg(flag: &str, value: Option<String>) -> Result<usize, Box<dyn E
     Ok(value.parse::<usize>()?)
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let mut config = PerfConfig::default();
-    let mut output_path = PathBuf::from("build/perf/current_baseline.json");
+fn parse_f64_arg(flag: &str, value: Option<String>) -> Result<f64, Box<dyn Error>> {
+    let value = value.ok_or_else(|| format!("{flag} には数値が必要です"))?;
+    Ok(value.parse::<f64>()?)
+}
+
+/// Mirrors `PerfConfig` plus the output/comparison settings, but every field is optional so a
+/// profile checked into the repo only needs to specify what it overrides. `deny_unknown_fields`
+/// surfaces typos instead of silently dropping them.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PerfConfigFile {
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    fail_threshold_pct: Option<f64>,
+    keep_outliers: Option<bool>,
+    warmup_iterations: Option<u32>,
+    iterations: Option<u32>,
+    sprite_count: Option<usize>,
+    ui_items_per_row: Option<usize>,
+    ui_items_per_col: Option<usize>,
+    scene_entity_count: Option<usize>,
+    life_grid_width: Option<usize>,
+    life_grid_height: Option<usize>,
+    life_generations: Option<u32>,
+    gpu_benchmark: Option<bool>,
+}
+
+/// Parses `--config <path>` as TOML or JSON5, dispatching on the file extension. JSON5 is a
+/// superset of JSON, so plain JSON profiles parse through the same `json5` path.
+fn load_config_file(path: &PathBuf) -> Result<PerfConfigFile, Box<dyn Error>> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("--config {} の読み込みに失敗しました: {e}", path.display()))?;
+
+    let is_toml = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.eq_ignore_ascii_case("toml"))
+        .unwrap_or(false);
+
+    if is_toml {
+        toml::from_str(&contents)
+            .map_err(|e| format!("--config {} のTOML解析に失敗しました: {e}", path.display()).into())
+    } else {
+        json5::from_str(&contents)
+            .map_err(|e| format!("--config {} のJSON5解析に失敗しました: {e}", path.display()).into())
+    }
+}
+
+struct CliOverrides {
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    fail_threshold_pct: Option<f64>,
+    keep_outliers: bool,
+    warmup_iterations: Option<u32>,
+    iterations: Option<u32>,
+    sprite_count: Option<usize>,
+    ui_items_per_row: Option<usize>,
+    ui_items_per_col: Option<usize>,
+    scene_entity_count: Option<usize>,
+    life_grid_width: Option<usize>,
+    life_grid_height: Option<usize>,
+    life_generations: Option<u32>,
+    no_progress: bool,
+    format: Option<OutputFormat>,
+    run_id: Option<String>,
+    gpu_benchmark: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Json,
+    Csv,
+    Ndjson,
+}
+
+impl std::str::FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            "ndjson" => Ok(OutputFormat::Ndjson),
+            other => Err(format!("未対応の--formatです: {other} (json/csv/ndjsonのいずれか)")),
+        }
+    }
+}
+
+fn main() -> Result<ExitCode, Box<dyn Error>> {
+    let mut config_path: Option<PathBuf> = None;
+    let mut overrides = CliOverrides {
+        output: None,
+        baseline: None,
+        fail_threshold_pct: None,
+        keep_outliers: false,
+        warmup_iterations: None,
+        iterations: None,
+        sprite_count: None,
+        ui_items_per_row: None,
+        ui_items_per_col: None,
+        scene_entity_count: None,
+        life_grid_width: None,
+        life_grid_height: None,
+        life_generations: None,
+        no_progress: false,
+        format: None,
+        run_id: None,
+        gpu_benchmark: false,
+    };
 
     let mut args = env::args().skip(1);
     while let Some(arg) = args.next() {
         match arg.as_str() {
+            "--config" => {
+                config_path = Some(PathBuf::from(
+                    args.next()
+                        .ok_or_else(|| "--config にはファイルパスが必要です".to_string())?,
+                ));
+            }
             "--output" => {
-                output_path = PathBuf::from(
+                overrides.output = Some(PathBuf::from(
                     args.next()
                         .ok_or_else(|| "--output には出力パスが必要です".to_string())?,
+                ));
+            }
+            "--baseline" => {
+                overrides.baseline = Some(PathBuf::from(
+                    args.next()
+                        .ok_or_else(|| "--baseline には比較元パスが必要です".to_string())?,
+                ));
+            }
+            "--fail-threshold" => {
+                overrides.fail_threshold_pct = Some(parse_f64_arg("--fail-threshold", args.next())?);
+            }
+            "--keep-outliers" => {
+                overrides.keep_outliers = true;
+            }
+            "--no-progress" => {
+                overrides.no_progress = true;
+            }
+            "--format" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| "--format には json/csv/ndjson のいずれかが必要です".to_string())?;
+                overrides.format = Some(value.parse()?);
+            }
+            "--run-id" => {
+                overrides.run_id = Some(
+                    args.next()
+                        .ok_or_else(|| "--run-id には識別子が必要です".to_string())?,
                 );
             }
             "--iterations" => {
-                config.iterations = parse_u32_arg("--iterations", args.next())?;
+                overrides.iterations = Some(parse_u32_arg("--iterations", args.next())?);
             }
             "--warmup" => {
-                config.warmup_iterations = parse_u32_arg("--warmup", args.next())?;
+                overrides.warmup_iterations = Some(parse_u32_arg("--warmup", args.next())?);
             }
             "--sprite-count" => {
-                config.sprite_count = parse_usize_arg("--sprite-count", args.next())?;
+                overrides.sprite_count = Some(parse_usize_arg("--sprite-count", args.next())?);
             }
             "--ui-rows" => {
-                config.ui_items_per_row = parse_usize_arg("--ui-rows", args.next())?;
+                overrides.ui_items_per_row = Some(parse_usize_arg("--ui-rows", args.next())?);
             }
             "--ui-cols" => {
-                config.ui_items_per_col = parse_usize_arg("--ui-cols", args.next())?;
+                overrides.ui_items_per_col = Some(parse_usize_arg("--ui-cols", args.next())?);
             }
             "--scene-entities" => {
-                config.scene_entity_count = parse_usize_arg("--scene-entities", args.next())?;
+                overrides.scene_entity_count = Some(parse_usize_arg("--scene-entities", args.next())?);
+            }
+            "--gpu" => {
+                overrides.gpu_benchmark = true;
+            }
+            "--grid-width" => {
+                overrides.life_grid_width = Some(parse_usize_arg("--grid-width", args.next())?);
+            }
+            "--grid-height" => {
+                overrides.life_grid_height = Some(parse_usize_arg("--grid-height", args.next())?);
+            }
+            "--life-generations" => {
+                overrides.life_generations = Some(parse_u32_arg("--life-generations", args.next())?);
             }
             "--help" | "-h" => {
                 print_help();
-                return Ok(());
+                return Ok(ExitCode::SUCCESS);
             }
             _ => {
                 return Err(format!("未対応オプションです: {arg}").into());
@@ -55,37 +213,269 @@ fn main() -> Result<(), Box<dyn Error>> {
         }
     }
 
-    let report = run_performance_baseline(config);
+    let file_config = match &config_path {
+        Some(path) => load_config_file(path)?,
+        None => PerfConfigFile::default(),
+    };
+
+    let mut config = PerfConfig::default();
+    config.warmup_iterations = overrides
+        .warmup_iterations
+        .or(file_config.warmup_iterations)
+        .unwrap_or(config.warmup_iterations);
+    config.iterations = overrides
+        .iterations
+        .or(file_config.iterations)
+        .unwrap_or(config.iterations);
+    config.sprite_count = overrides
+        .sprite_count
+        .or(file_config.sprite_count)
+        .unwrap_or(config.sprite_count);
+    config.ui_items_per_row = overrides
+        .ui_items_per_row
+        .or(file_config.ui_items_per_row)
+        .unwrap_or(config.ui_items_per_row);
+    config.ui_items_per_col = overrides
+        .ui_items_per_col
+        .or(file_config.ui_items_per_col)
+        .unwrap_or(config.ui_items_per_col);
+    config.scene_entity_count = overrides
+        .scene_entity_count
+        .or(file_config.scene_entity_count)
+        .unwrap_or(config.scene_entity_count);
+    config.keep_outliers = overrides.keep_outliers || file_config.keep_outliers.unwrap_or(false);
+    config.gpu_benchmark =
+        overrides.gpu_benchmark || file_config.gpu_benchmark.unwrap_or(false);
+    config.life_grid_width = overrides
+        .life_grid_width
+        .or(file_config.life_grid_width)
+        .unwrap_or(config.life_grid_width);
+    config.life_grid_height = overrides
+        .life_grid_height
+        .or(file_config.life_grid_height)
+        .unwrap_or(config.life_grid_height);
+    config.life_generations = overrides
+        .life_generations
+        .or(file_config.life_generations)
+        .unwrap_or(config.life_generations);
+
+    let output_path = overrides
+        .output
+        .or(file_config.output)
+        .unwrap_or_else(|| PathBuf::from("build/perf/current_baseline.json"));
+    let baseline_path = overrides.baseline.or(file_config.baseline);
+    let fail_threshold_pct = overrides
+        .fail_threshold_pct
+        .or(file_config.fail_threshold_pct)
+        .unwrap_or(DEFAULT_FAIL_THRESHOLD_PCT);
+    let format = overrides.format.unwrap_or(OutputFormat::Json);
+    let run_id = overrides.run_id.unwrap_or_else(|| "local".to_string());
+
+    let show_progress = !overrides.no_progress && io::stdout().is_terminal();
+    let report = run_performance_baseline_with_progress(config, |event| {
+        if show_progress {
+            print_progress(&event);
+        }
+    });
+    if show_progress {
+        // Clear the in-place progress line before the final machine-readable summary.
+        print!("\r\x1b[2K");
+        let _ = io::stdout().flush();
+    }
 
     if let Some(parent) = output_path.parent() {
         fs::create_dir_all(parent)?;
     }
-    let json = serde_json::to_string_pretty(&report)?;
-    fs::write(&output_path, json)?;
+    write_report(&output_path, &report, format, &run_id)?;
 
-    println!("[perf] report={}", output_path.display());
+    println!("[perf] report={} format={:?} run_id={run_id}", output_path.display(), format);
     for scenario in &report.scenarios {
         println!(
-            "[perf] scenario={} avg_ms={:.3} p95_ms={:.3} min_ms={:.3} max_ms={:.3} iterations={}",
+            "[perf] scenario={} avg_ms={:.3} p50_ms={:.3} p95_ms={:.3} p99_ms={:.3} min_ms={:.3} max_ms={:.3} stddev_ms={:.3} mild_outliers={} severe_outliers={} iterations={}",
             scenario.name,
             scenario.avg_ms,
+            scenario.p50_ms,
             scenario.p95_ms,
+            scenario.p99_ms,
             scenario.min_ms,
             scenario.max_ms,
+            scenario.stddev_ms,
+            scenario.mild_outliers,
+            scenario.severe_outliers,
             scenario.iterations
         );
     }
 
+    let Some(baseline_path) = baseline_path else {
+        return Ok(ExitCode::SUCCESS);
+    };
+
+    let baseline_json = fs::read(&baseline_path)
+        .map_err(|e| format!("--baseline {} の読み込みに失敗しました: {e}", baseline_path.display()))?;
+    let baseline: PerfReport = serde_json::from_slice(&baseline_json)?;
+
+    let regressed = print_comparison_table(&baseline, &report, fail_threshold_pct);
+    if regressed {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+/// Writes `report` to `path` in the requested format. CSV emits one row per scenario with a
+/// header; NDJSON emits one JSON object per scenario per line. Both embed `run_id` so rows from
+/// several appended runs can be told apart when building a trend database.
+fn write_report(
+    path: &PathBuf,
+    report: &PerfReport,
+    format: OutputFormat,
+    run_id: &str,
+) -> Result<(), Box<dyn Error>> {
+    match format {
+        OutputFormat::Json => {
+            let json = serde_json::to_string_pretty(report)?;
+            fs::write(path, json)?;
+        }
+        OutputFormat::Csv => {
+            let mut out = String::new();
+            out.push_str("run_id,name,iterations,avg_ms,p50_ms,p95_ms,p99_ms,min_ms,max_ms\n");
+            for scenario in &report.scenarios {
+                out.push_str(&format!(
+                    "{run_id},{},{},{},{},{},{},{},{}\n",
+                    scenario.name,
+                    scenario.iterations,
+                    scenario.avg_ms,
+                    scenario.p50_ms,
+                    scenario.p95_ms,
+                    scenario.p99_ms,
+                    scenario.min_ms,
+                    scenario.max_ms,
+                ));
+            }
+            fs::write(path, out)?;
+        }
+        OutputFormat::Ndjson => {
+            let mut out = String::new();
+            for scenario in &report.scenarios {
+                let row = serde_json::json!({
+                    "run_id": run_id,
+                    "generated_unix_epoch_sec": report.generated_unix_epoch_sec,
+                    "name": scenario.name,
+                    "iterations": scenario.iterations,
+                    "avg_ms": scenario.avg_ms,
+                    "p50_ms": scenario.p50_ms,
+                    "p95_ms": scenario.p95_ms,
+                    "p99_ms": scenario.p99_ms,
+                    "min_ms": scenario.min_ms,
+                    "max_ms": scenario.max_ms,
+                });
+                out.push_str(&row.to_string());
+                out.push('\n');
+            }
+            fs::write(path, out)?;
+        }
+    }
     Ok(())
 }
 
+/// Compares two reports scenario-by-scenario and prints a regressed/improved/delta table.
+/// Returns true if any matched scenario's `p95_ms` regressed beyond `fail_threshold_pct`.
+fn print_comparison_table(baseline: &PerfReport, candidate: &PerfReport, fail_threshold_pct: f64) -> bool {
+    println!(
+        "[perf] comparison baseline={} fail_threshold_pct={:.1}",
+        "loaded", fail_threshold_pct
+    );
+    println!(
+        "{:<32} {:>10} {:>12} {:>12}",
+        "scenario", "status", "avg_delta%", "p95_delta%"
+    );
+
+    let mut any_regressed = false;
+
+    for candidate_scenario in &candidate.scenarios {
+        let Some(baseline_scenario) = baseline
+            .scenarios
+            .iter()
+            .find(|s| s.name == candidate_scenario.name)
+        else {
+            println!("{:<32} {:>10}", candidate_scenario.name, "added");
+            continue;
+        };
+
+        let avg_delta_pct = percent_change(baseline_scenario.avg_ms, candidate_scenario.avg_ms);
+        let p95_delta_pct = percent_change(baseline_scenario.p95_ms, candidate_scenario.p95_ms);
+        let regressed = p95_delta_pct > fail_threshold_pct;
+        if regressed {
+            any_regressed = true;
+        }
+        let status = if regressed {
+            "regressed"
+        } else if p95_delta_pct < 0.0 {
+            "improved"
+        } else {
+            "ok"
+        };
+
+        println!(
+            "{:<32} {:>10} {:>11.2}% {:>11.2}%",
+            candidate_scenario.name, status, avg_delta_pct, p95_delta_pct
+        );
+    }
+
+    for baseline_scenario in &baseline.scenarios {
+        let still_present = candidate
+            .scenarios
+            .iter()
+            .any(|s| s.name == baseline_scenario.name);
+        if !still_present {
+            println!("{:<32} {:>10}", baseline_scenario.name, "removed");
+        }
+    }
+
+    any_regressed
+}
+
+/// Redraws a single in-place progress line for the scenario currently being benchmarked.
+fn print_progress(event: &ProgressEvent) {
+    let phase_label = match event.phase {
+        BenchmarkPhase::Warmup => "warmup",
+        BenchmarkPhase::Measure => "measure",
+    };
+    print!(
+        "\r\x1b[2K[perf] {} {phase_label} {}/{} eta={:.1}s",
+        event.scenario,
+        event.current,
+        event.total,
+        event.estimated_remaining.as_secs_f64()
+    );
+    let _ = io::stdout().flush();
+}
+
+fn percent_change(baseline_ms: f64, candidate_ms: f64) -> f64 {
+    if baseline_ms == 0.0 {
+        return 0.0;
+    }
+    ((candidate_ms - baseline_ms) / baseline_ms) * 100.0
+}
+
 fn print_help() {
     println!("Usage: perf_baseline [options]");
+    println!("  --config <path>         ベンチ設定を読み込むTOML/JSON5ファイル (CLIフラグが優先)");
     println!("  --output <path>         JSON出力先 (default: build/perf/current_baseline.json)");
+    println!("  --baseline <path>       比較対象のベースラインJSON (指定時のみ比較モードで動作)");
+    println!("  --fail-threshold <pct>  p95_msの許容回帰率 (default: 10.0)");
+    println!("  --keep-outliers         Tukey fenceによる外れ値除去を無効化する");
+    println!("  --no-progress           TTY接続時でも進捗表示を無効化する");
+    println!("  --format <fmt>          出力形式 json/csv/ndjson (default: json)");
+    println!("  --run-id <id>           CSV/NDJSON行に付与する実行識別子 (default: local)");
     println!("  --iterations <n>        計測反復数 (default: 30)");
     println!("  --warmup <n>            ウォームアップ反復数 (default: 5)");
     println!("  --sprite-count <n>      スプライト数 (default: 10000)");
     println!("  --ui-rows <n>           UI行数 (default: 30)");
     println!("  --ui-cols <n>           UI列数 (default: 40)");
     println!("  --scene-entities <n>    シーン構築破棄のエンティティ数 (default: 5000)");
+    println!("  --gpu                   gpu_draw_callシナリオを追加実行する (要 gpu_bench feature)");
+    println!("  --grid-width <n>        ライフゲームの盤面幅 (default: 256)");
+    println!("  --grid-height <n>       ライフゲームの盤面高さ (default: 256)");
+    println!("  --life-generations <n>  1計測あたりに進めるライフゲームの世代数 (default: 10)");
 }
@@ -0,0 +1,68 @@
+//! Camera frustum (2D AABB) culling: trims the entity set fed into `build_renderables_from_world`
+//! down to only what's actually visible, instead of building a `RenderableObject` for every entity
+//! in the world regardless of whether the camera can see it.
+
+use crate::{ffi, ComponentType, InternalWorld};
+
+/// Axis-aligned viewport box in world space, expanded by `margin` on every side before the
+/// intersection test — a small buffer so entities just offscreen don't pop in/out on the exact
+/// frame they cross the edge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraBounds {
+    pub min: ffi::Vec2,
+    pub max: ffi::Vec2,
+    pub margin: f32,
+}
+
+impl CameraBounds {
+    fn intersects(&self, min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> bool {
+        let cam_min_x = self.min.x - self.margin;
+        let cam_min_y = self.min.y - self.margin;
+        let cam_max_x = self.max.x + self.margin;
+        let cam_max_y = self.max.y + self.margin;
+        min_x <= cam_max_x && max_x >= cam_min_x && min_y <= cam_max_y && max_y >= cam_min_y
+    }
+}
+
+/// Where one surviving entity lives in `InternalWorld::archetypes`: the same `(archetype, row)`
+/// addressing `InternalWorld::entities` already uses internally, just named for readability here.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EntityIndex {
+    pub archetype: usize,
+    pub row: usize,
+}
+
+/// Keeps every `Transform`-carrying entity whose world-space AABB (`position ± scale/2`, ignoring
+/// the z axis) intersects `camera`. An entity with no `Transform` can't be placed in the world, so
+/// it can't be culled either — it's simply never a candidate.
+pub fn cull_world(world: &InternalWorld, camera: &CameraBounds) -> Vec<EntityIndex> {
+    let mut visible = Vec::new();
+
+    for (archetype_idx, archetype) in world.archetypes.iter().enumerate() {
+        if !archetype.types.contains(&ComponentType::Transform) {
+            continue;
+        }
+        let Some(transforms) = archetype
+            .storage
+            .get(&ComponentType::Transform)
+            .and_then(|storage| storage.downcast_ref::<Vec<ffi::Transform>>())
+        else {
+            continue;
+        };
+
+        for (row, transform) in transforms.iter().enumerate() {
+            let half_x = transform.scale.x * 0.5;
+            let half_y = transform.scale.y * 0.5;
+            let min_x = transform.position.x - half_x;
+            let max_x = transform.position.x + half_x;
+            let min_y = transform.position.y - half_y;
+            let max_y = transform.position.y + half_y;
+
+            if camera.intersects(min_x, min_y, max_x, max_y) {
+                visible.push(EntityIndex { archetype: archetype_idx, row });
+            }
+        }
+    }
+
+    visible
+}
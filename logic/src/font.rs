@@ -0,0 +1,172 @@
+//! BMFont-style bitmap font metrics, loaded through the asset server and used to measure text for
+//! real alignment instead of `ui_system`'s old `len() * 6.0` guess.
+//!
+//! Only the text-format BMFont descriptor (`.fnt`) is supported, parsed line by line as
+//! `key=value` pairs; the actual glyph atlas texture is loaded separately through
+//! `AssetServer::load_texture` like any other image, the same split the BMFont format itself
+//! makes between the `.fnt` metrics file and its `page` image(s).
+
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlyphMetrics {
+    pub advance: f32,
+    pub xoffset: f32,
+    pub yoffset: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontMetrics {
+    pub line_height: f32,
+    pub base: f32,
+    glyphs: HashMap<char, GlyphMetrics>,
+    kerning: HashMap<(char, char), f32>,
+}
+
+#[derive(Debug)]
+pub enum FontError {
+    Io(std::io::Error),
+    Parse(String),
+}
+
+impl Display for FontError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontError::Io(e) => write!(f, "I/O error: {e}"),
+            FontError::Parse(msg) => write!(f, "parse error: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FontError {}
+
+impl From<std::io::Error> for FontError {
+    fn from(value: std::io::Error) -> Self {
+        FontError::Io(value)
+    }
+}
+
+/// Pulls `key=value` and `key="quoted value"` pairs out of one BMFont descriptor line, ignoring
+/// the leading tag (`char`, `kerning`, `common`, ...).
+fn parse_attrs(line: &str) -> HashMap<&str, &str> {
+    let mut attrs = HashMap::new();
+    let mut rest = line.trim();
+    loop {
+        let Some(eq) = rest.find('=') else { break };
+        let key = rest[..eq].trim();
+        rest = rest[eq + 1..].trim_start();
+        let (value, tail) = if rest.starts_with('"') {
+            match rest[1..].find('"') {
+                Some(end) => (&rest[1..1 + end], &rest[2 + end..]),
+                None => (rest, ""),
+            }
+        } else {
+            match rest.find(char::is_whitespace) {
+                Some(end) => (&rest[..end], &rest[end..]),
+                None => (rest, ""),
+            }
+        };
+        attrs.insert(key, value);
+        rest = tail.trim_start();
+        if rest.is_empty() {
+            break;
+        }
+    }
+    attrs
+}
+
+fn attr_f32(attrs: &HashMap<&str, &str>, key: &str) -> Option<f32> {
+    attrs.get(key).and_then(|v| v.parse::<f32>().ok())
+}
+
+fn attr_char(attrs: &HashMap<&str, &str>, key: &str) -> Option<char> {
+    attr_f32(attrs, key).and_then(|id| char::from_u32(id as u32))
+}
+
+impl FontMetrics {
+    /// Parses a BMFont text-format (`.fnt`) descriptor: `common` for `lineHeight`/`base`, one
+    /// `char` line per glyph's advance/offset/size, one `kerning` line per adjustment pair.
+    /// Unrecognized lines (`info`, `page`, the `chars`/`kernings` count headers) are ignored.
+    pub fn parse(text: &str) -> Result<Self, FontError> {
+        let mut line_height = 0.0_f32;
+        let mut base = 0.0_f32;
+        let mut glyphs = HashMap::new();
+        let mut kerning = HashMap::new();
+
+        for line in text.lines() {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("common ") {
+                let attrs = parse_attrs(rest);
+                line_height = attr_f32(&attrs, "lineHeight").unwrap_or(0.0);
+                base = attr_f32(&attrs, "base").unwrap_or(0.0);
+            } else if let Some(rest) = line.strip_prefix("char ") {
+                let attrs = parse_attrs(rest);
+                let Some(id) = attr_char(&attrs, "id") else { continue };
+                glyphs.insert(
+                    id,
+                    GlyphMetrics {
+                        advance: attr_f32(&attrs, "xadvance").unwrap_or(0.0),
+                        xoffset: attr_f32(&attrs, "xoffset").unwrap_or(0.0),
+                        yoffset: attr_f32(&attrs, "yoffset").unwrap_or(0.0),
+                        width: attr_f32(&attrs, "width").unwrap_or(0.0),
+                        height: attr_f32(&attrs, "height").unwrap_or(0.0),
+                    },
+                );
+            } else if let Some(rest) = line.strip_prefix("kerning ") {
+                let attrs = parse_attrs(rest);
+                let (Some(first), Some(second), Some(amount)) = (
+                    attr_char(&attrs, "first"),
+                    attr_char(&attrs, "second"),
+                    attr_f32(&attrs, "amount"),
+                ) else {
+                    continue;
+                };
+                kerning.insert((first, second), amount);
+            }
+        }
+
+        if line_height <= 0.0 {
+            return Err(FontError::Parse("missing or zero `common lineHeight`".to_string()));
+        }
+
+        Ok(Self { line_height, base, glyphs, kerning })
+    }
+
+    /// Loads and parses a BMFont descriptor from disk.
+    pub fn load(path: &Path) -> Result<Self, FontError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    fn advance_for(&self, c: char) -> f32 {
+        self.glyphs.get(&c).map(|g| g.advance).unwrap_or(self.line_height * 0.5)
+    }
+
+    /// Measures `text`'s pixel width/height from glyph advances and kerning pairs, iterating
+    /// `chars()` (not bytes) so multibyte UTF-8 glyphs are counted once each, not once per byte.
+    /// Width is the widest line; height is `line_height` times the number of lines.
+    pub fn measure(&self, text: &str) -> (f32, f32) {
+        let mut max_width = 0.0_f32;
+        let mut line_count = 0usize;
+
+        for line in text.split('\n') {
+            line_count += 1;
+            let mut width = 0.0_f32;
+            let mut prev: Option<char> = None;
+            for c in line.chars() {
+                if let Some(prev) = prev {
+                    width += self.kerning.get(&(prev, c)).copied().unwrap_or(0.0);
+                }
+                width += self.advance_for(c);
+                prev = Some(c);
+            }
+            max_width = max_width.max(width);
+        }
+
+        (max_width, self.line_height * line_count.max(1) as f32)
+    }
+}
@@ -0,0 +1,162 @@
+//! Optional gameplay-tuning layer, gated behind the `scripting` cargo feature. Without that
+//! feature `update_in_game`'s hard-coded difficulty curve and win/lose check are the only
+//! behavior; with it, a loaded `GameplayScript` gets first refusal each fixed tick and can
+//! override the difficulty level, spawn obstacles directly, or decide the run is won or lost —
+//! the same hot-reloadable, live-editable-layer idea as `script.rs`'s cutscene VM, but aimed at
+//! in-run tuning instead of scripted text/cutscene sequences.
+//!
+//! Scripts are plain text, one rule per line:
+//! ```text
+//! # win after 30 minutes, lose at 0 HP
+//! WHEN survival_at_least 1800 THEN win
+//! WHEN hp_at_most 0 THEN lose
+//! WHEN avoid_at_least 20 THEN set_difficulty 5
+//! WHEN always THEN spawn_obstacle 400 140
+//! ```
+//! `#` starts a comment; blank lines are ignored. Rules are evaluated top to bottom every tick;
+//! `Game::run_gameplay_script` runs every matching `set_difficulty`/`spawn_obstacle` rule and
+//! stops at the first matching terminal action (`win`/`lose`); rules below it are not run.
+
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Condition {
+    Always,
+    SurvivalAtLeast(f32),
+    HpAtMost(i32),
+    AvoidAtLeast(u32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    Win,
+    Lose,
+    SetDifficulty(u32),
+    SpawnObstacle { x: f32, speed: f32 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rule {
+    pub condition: Condition,
+    pub action: Action,
+}
+
+#[derive(Debug)]
+pub enum GameplayScriptError {
+    Io(std::io::Error),
+    UnknownCondition(String),
+    UnknownAction(String),
+    InvalidArgument(String),
+}
+
+impl Display for GameplayScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GameplayScriptError::Io(e) => write!(f, "I/O error: {e}"),
+            GameplayScriptError::UnknownCondition(c) => write!(f, "unknown condition: {c}"),
+            GameplayScriptError::UnknownAction(a) => write!(f, "unknown action: {a}"),
+            GameplayScriptError::InvalidArgument(line) => {
+                write!(f, "invalid argument on line: {line}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GameplayScriptError {}
+
+impl From<std::io::Error> for GameplayScriptError {
+    fn from(value: std::io::Error) -> Self {
+        GameplayScriptError::Io(value)
+    }
+}
+
+/// A parsed set of tuning rules, see the module doc for the format.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GameplayScript {
+    rules: Vec<Rule>,
+}
+
+impl GameplayScript {
+    pub fn load(path: &Path) -> Result<Self, GameplayScriptError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    pub fn parse(source: &str) -> Result<Self, GameplayScriptError> {
+        let mut rules = Vec::new();
+        for raw_line in source.lines() {
+            let line = raw_line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            rules.push(parse_rule(line)?);
+        }
+        Ok(GameplayScript { rules })
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+}
+
+fn parse_rule(line: &str) -> Result<Rule, GameplayScriptError> {
+    let rest = line
+        .strip_prefix("WHEN ")
+        .ok_or_else(|| GameplayScriptError::InvalidArgument(line.to_string()))?;
+    let (condition_part, action_part) = rest
+        .split_once(" THEN ")
+        .ok_or_else(|| GameplayScriptError::InvalidArgument(line.to_string()))?;
+
+    Ok(Rule {
+        condition: parse_condition(condition_part.trim())?,
+        action: parse_action(action_part.trim())?,
+    })
+}
+
+fn parse_condition(text: &str) -> Result<Condition, GameplayScriptError> {
+    let (keyword, arg) = text.split_once(' ').unwrap_or((text, ""));
+    let arg = arg.trim();
+    match keyword {
+        "always" => Ok(Condition::Always),
+        "survival_at_least" => arg
+            .parse::<f32>()
+            .map(Condition::SurvivalAtLeast)
+            .map_err(|_| GameplayScriptError::InvalidArgument(text.to_string())),
+        "hp_at_most" => arg
+            .parse::<i32>()
+            .map(Condition::HpAtMost)
+            .map_err(|_| GameplayScriptError::InvalidArgument(text.to_string())),
+        "avoid_at_least" => arg
+            .parse::<u32>()
+            .map(Condition::AvoidAtLeast)
+            .map_err(|_| GameplayScriptError::InvalidArgument(text.to_string())),
+        _ => Err(GameplayScriptError::UnknownCondition(keyword.to_string())),
+    }
+}
+
+fn parse_action(text: &str) -> Result<Action, GameplayScriptError> {
+    let (keyword, rest) = text.split_once(' ').unwrap_or((text, ""));
+    let rest = rest.trim();
+    match keyword {
+        "win" => Ok(Action::Win),
+        "lose" => Ok(Action::Lose),
+        "set_difficulty" => rest
+            .parse::<u32>()
+            .map(Action::SetDifficulty)
+            .map_err(|_| GameplayScriptError::InvalidArgument(text.to_string())),
+        "spawn_obstacle" => {
+            let mut parts = rest.split_whitespace();
+            let x = parts
+                .next()
+                .and_then(|s| s.parse::<f32>().ok())
+                .ok_or_else(|| GameplayScriptError::InvalidArgument(text.to_string()))?;
+            let speed = parts
+                .next()
+                .and_then(|s| s.parse::<f32>().ok())
+                .ok_or_else(|| GameplayScriptError::InvalidArgument(text.to_string()))?;
+            Ok(Action::SpawnObstacle { x, speed })
+        }
+        _ => Err(GameplayScriptError::UnknownAction(keyword.to_string())),
+    }
+}
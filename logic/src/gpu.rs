@@ -0,0 +1,181 @@
+//! std140-packed GPU instance buffers: `build_renderables`/`build_renderables_from_world` hand back
+//! `RenderableObject`s in host-native layout, which a uniform/storage buffer binding can't consume
+//! directly — std140 requires a `vec3` to occupy a `vec4`-sized, `vec4`-aligned slot, and the
+//! overall record size to round up to its largest member's alignment. `pack_instances_std140`
+//! does that restride once, on the host, so the GPU side can upload the result verbatim.
+
+use crate::ffi;
+
+/// Bytes per packed instance: a 64-byte column-major `mat4x4<f32>` model matrix, followed by
+/// `texture_id` and `material_id`, each padded out to its own 16-byte std140 slot.
+pub const INSTANCE_STRIDE: usize = 64 + 16 + 16;
+
+/// Serializes each `renderable`'s transform as a column-major `mat4x4<f32>` model matrix (rotation
+/// applied as XYZ-order Euler angles, in radians), then `texture_id` and `material_id`, each
+/// std140-padded to a 16-byte slot so the whole record stays aligned on the 16-byte boundary the
+/// leading `mat4x4` member requires.
+pub fn pack_instances_std140(renderables: &[ffi::RenderableObject]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(renderables.len() * INSTANCE_STRIDE);
+
+    for renderable in renderables {
+        for value in model_matrix(&renderable.transform) {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&renderable.texture_id.to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 12]);
+
+        bytes.extend_from_slice(&renderable.material_id.to_le_bytes());
+        bytes.extend_from_slice(&[0u8; 12]);
+    }
+
+    bytes
+}
+
+/// Column-major `mat4x4<f32>` (16 floats, first column first) for `Translate * Rotate(XYZ) *
+/// Scale`, the usual TRS model-matrix composition.
+fn model_matrix(transform: &ffi::Transform) -> [f32; 16] {
+    let (scale_x, scale_y, scale_z) = (transform.scale.x, transform.scale.y, transform.scale.z);
+
+    let (cos_x, sin_x) = (transform.rotation.x.cos(), transform.rotation.x.sin());
+    let (cos_y, sin_y) = (transform.rotation.y.cos(), transform.rotation.y.sin());
+    let (cos_z, sin_z) = (transform.rotation.z.cos(), transform.rotation.z.sin());
+
+    // Rotate = Rz * Ry * Rx.
+    let r00 = cos_y * cos_z;
+    let r01 = cos_y * sin_z;
+    let r02 = -sin_y;
+    let r10 = sin_x * sin_y * cos_z - cos_x * sin_z;
+    let r11 = sin_x * sin_y * sin_z + cos_x * cos_z;
+    let r12 = sin_x * cos_y;
+    let r20 = cos_x * sin_y * cos_z + sin_x * sin_z;
+    let r21 = cos_x * sin_y * sin_z - sin_x * cos_z;
+    let r22 = cos_x * cos_y;
+
+    [
+        r00 * scale_x,
+        r01 * scale_x,
+        r02 * scale_x,
+        0.0,
+        r10 * scale_y,
+        r11 * scale_y,
+        r12 * scale_y,
+        0.0,
+        r20 * scale_z,
+        r21 * scale_z,
+        r22 * scale_z,
+        0.0,
+        transform.position.x,
+        transform.position.y,
+        transform.position.z,
+        1.0,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn renderable(transform: ffi::Transform) -> ffi::RenderableObject {
+        ffi::RenderableObject {
+            mesh_id: 1,
+            material_id: 7,
+            texture_id: 3,
+            transform,
+            color: ffi::Vec4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 },
+            atlas_layer: 0,
+            uv_min: ffi::Vec2 { x: 0.0, y: 0.0 },
+            uv_max: ffi::Vec2 { x: 1.0, y: 1.0 },
+        }
+    }
+
+    fn decode_floats(bytes: &[u8]) -> Vec<f32> {
+        bytes
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+            .collect()
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-5,
+            "expected {expected}, got {actual}"
+        );
+    }
+
+    #[test]
+    fn stride_and_length_match_one_record_per_instance() {
+        let instances = vec![
+            renderable(identity_transform()),
+            renderable(identity_transform()),
+        ];
+
+        let bytes = pack_instances_std140(&instances);
+
+        assert_eq!(INSTANCE_STRIDE, 96);
+        assert_eq!(bytes.len(), instances.len() * INSTANCE_STRIDE);
+    }
+
+    fn identity_transform() -> ffi::Transform {
+        ffi::Transform {
+            position: ffi::Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+            rotation: ffi::Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+            scale: ffi::Vec3 { x: 1.0, y: 1.0, z: 1.0 },
+        }
+    }
+
+    #[test]
+    fn identity_transform_packs_to_the_identity_matrix_plus_ids() {
+        let instance = renderable(identity_transform());
+        let bytes = pack_instances_std140(std::slice::from_ref(&instance));
+
+        assert_eq!(bytes.len(), INSTANCE_STRIDE);
+
+        let matrix = decode_floats(&bytes[0..64]);
+        #[rustfmt::skip]
+        let expected = [
+            1.0, 0.0, 0.0, 0.0,
+            0.0, 1.0, 0.0, 0.0,
+            0.0, 0.0, 1.0, 0.0,
+            0.0, 0.0, 0.0, 1.0,
+        ];
+        for (actual, expected) in matrix.iter().zip(expected.iter()) {
+            assert_close(*actual, *expected);
+        }
+
+        let texture_id = u32::from_le_bytes(bytes[64..68].try_into().unwrap());
+        let material_id = u32::from_le_bytes(bytes[80..84].try_into().unwrap());
+        assert_eq!(texture_id, instance.texture_id);
+        assert_eq!(material_id, instance.material_id);
+
+        // std140 padding: the 12 bytes following each scalar id stay zeroed.
+        assert_eq!(&bytes[68..80], &[0u8; 12]);
+        assert_eq!(&bytes[84..96], &[0u8; 12]);
+    }
+
+    #[test]
+    fn scaled_and_rotated_transform_matches_hand_computed_matrix() {
+        let transform = ffi::Transform {
+            position: ffi::Vec3 { x: 10.0, y: 20.0, z: 30.0 },
+            rotation: ffi::Vec3 { x: 0.0, y: 0.0, z: std::f32::consts::FRAC_PI_2 },
+            scale: ffi::Vec3 { x: 2.0, y: 3.0, z: 4.0 },
+        };
+        let instance = renderable(transform);
+        let bytes = pack_instances_std140(std::slice::from_ref(&instance));
+        let matrix = decode_floats(&bytes[0..64]);
+
+        // A 90-degree Z rotation turns +X into +Y and +Y into -X, each scaled by its own axis:
+        // column 0 (scaled local X axis) lands on +Y*scale_x, column 1 (scaled local Y axis)
+        // lands on -X*scale_y.
+        #[rustfmt::skip]
+        let expected = [
+            0.0, 2.0, 0.0, 0.0,
+            -3.0, 0.0, 0.0, 0.0,
+            0.0, 0.0, 4.0, 0.0,
+            10.0, 20.0, 30.0, 1.0,
+        ];
+        for (actual, expected) in matrix.iter().zip(expected.iter()) {
+            assert_close(*actual, *expected);
+        }
+    }
+}
@@ -1,16 +1,35 @@
 mod paths;
+pub mod assetpack;
+pub mod atlas;
+pub mod batch;
+pub mod cull;
+pub mod font;
+pub mod gpu;
+pub mod locale;
+pub mod netplay;
+pub mod query;
+pub mod replay;
+pub mod rng;
+#[cfg(feature = "scripting")]
+pub mod gameplay_script;
 pub mod save;
+pub mod scene;
+pub mod script;
+pub mod stage;
 use crate::ui::Button;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::any::Any;
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::ptr;
 
 pub mod ui;
+#[cfg(feature = "scripting")]
+pub mod ui_script;
 
 // Forward-declare the C++ types.
 #[repr(C)]
@@ -25,28 +44,78 @@ pub struct AssetCommandSlice {
     len: usize,
 }
 
+/// Decompressed bytes for a bytes-backed `AssetCommand` (see `ffi::AssetDeliveryMode::Bytes`),
+/// handed to the host in place of a path it would otherwise have to open and read itself.
+#[repr(C)]
+pub struct AssetBytesSlice {
+    ptr: *const u8,
+    len: usize,
+}
+
 #[repr(C)]
 pub struct TextCommandSlice {
     ptr: *const ffi::TextCommand,
     len: usize,
 }
 
+#[repr(C)]
+pub struct RectCommandSlice {
+    ptr: *const ffi::RectCommand,
+    len: usize,
+}
+
+#[repr(C)]
+pub struct LightSlice {
+    ptr: *const ffi::Light,
+    len: usize,
+}
+
+#[repr(C)]
+pub struct DrawBatchSlice {
+    ptr: *const ffi::DrawBatch,
+    len: usize,
+}
+
+/// Per-instance transforms a `DrawBatchSlice` entry's `instance_start..instance_start +
+/// instance_count` indexes into, not `RenderableObjectSlice` itself.
+#[repr(C)]
+pub struct TransformSlice {
+    ptr: *const ffi::Transform,
+    len: usize,
+}
+
 #[repr(C)]
 pub struct MiyabiVTable {
     create_game: extern "C" fn() -> *mut Game,
     destroy_game: extern "C" fn(*mut Game),
     serialize_game: extern "C" fn(*const Game) -> *mut c_char,
     deserialize_game: extern "C" fn(*const c_char) -> *mut Game,
+    serialize_game_binary: extern "C" fn(*const Game) -> ByteBuffer,
+    deserialize_game_checked: extern "C" fn(*const u8, usize, *mut *mut Game) -> i32,
+    free_byte_buffer: extern "C" fn(ByteBuffer),
+    serialize_game_delta: extern "C" fn(*const Game, *const u8, usize, *mut *mut u8, *mut usize) -> i32,
+    apply_game_delta: extern "C" fn(*mut Game, *const u8, usize) -> i32,
+    free_game_delta_buffer: extern "C" fn(*mut u8, usize),
     free_serialized_string: extern "C" fn(*mut c_char),
     update_game: extern "C" fn(*mut Game) -> GameState,
     get_renderables: extern "C" fn(*mut Game) -> RenderableObjectSlice,
     get_asset_commands: extern "C" fn(*mut Game) -> AssetCommandSlice,
     clear_asset_commands: extern "C" fn(*mut Game),
     notify_asset_loaded: extern "C" fn(*mut Game, u32, u32),
+    notify_model_loaded: extern "C" fn(*mut Game, u32, u32),
+    notify_model_nodes_loaded: extern "C" fn(*mut Game, u32, *const ffi::ModelNode, usize),
     update_input_state: extern "C" fn(*mut Game, *const ffi::InputState),
     get_asset_command_path_cstring: extern "C" fn(*const ffi::AssetCommand) -> *mut c_char,
+    get_asset_command_bytes: extern "C" fn(*mut Game, *const ffi::AssetCommand) -> AssetBytesSlice,
+    mount_asset_pack: extern "C" fn(*mut Game, *const c_char) -> u32,
+    request_asset_reload: extern "C" fn(*mut Game, *const c_char) -> bool,
+    request_reload_all_dirty: extern "C" fn(*mut Game) -> usize,
     get_text_commands: extern "C" fn(*mut Game) -> TextCommandSlice,
     get_text_command_text_cstring: extern "C" fn(*const ffi::TextCommand) -> *mut c_char,
+    get_lights: extern "C" fn(*mut Game) -> LightSlice,
+    get_rect_commands: extern "C" fn(*mut Game) -> RectCommandSlice,
+    get_draw_batches: extern "C" fn(*mut Game) -> DrawBatchSlice,
+    get_batched_instances: extern "C" fn(*mut Game) -> TransformSlice,
     free_cstring: extern "C" fn(*mut c_char),
 }
 
@@ -57,16 +126,32 @@ pub extern "C" fn get_miyabi_vtable() -> MiyabiVTable {
         destroy_game,
         serialize_game,
         deserialize_game,
+        serialize_game_binary,
+        deserialize_game_checked,
+        free_byte_buffer,
+        serialize_game_delta,
+        apply_game_delta,
+        free_game_delta_buffer,
         free_serialized_string,
         update_game,
         get_renderables,
         get_asset_commands,
         clear_asset_commands,
         notify_asset_loaded,
+        notify_model_loaded,
+        notify_model_nodes_loaded,
         update_input_state,
         get_asset_command_path_cstring,
+        get_asset_command_bytes,
+        mount_asset_pack,
+        request_asset_reload,
+        request_reload_all_dirty,
         get_text_commands,
         get_text_command_text_cstring,
+        get_lights,
+        get_rect_commands,
+        get_draw_batches,
+        get_batched_instances,
         free_cstring,
     }
 }
@@ -75,16 +160,129 @@ pub trait Component: 'static + serde::Serialize + for<'de> serde::Deserialize<'d
     const COMPONENT_TYPE: ComponentType;
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum ComponentType {
-    Transform,
-    Velocity,
-    Material,
-    Player,
-    Obstacle,
-    Button,
-    Physics,
-    Sprite,
+/// Declares the engine's component type list in one place: the `ComponentType` enum, each
+/// type's `Component` impl, and the per-`ComponentType` storage operations
+/// (`init_storage`/`clear_storage`/`swap_remove_storage`/`copy_storage_row`/`clone_storage_row`)
+/// that used to be hand-written match ladders duplicated across `get_or_create_archetype`,
+/// `clear_entities_of_component`, `swap_remove_row`, and `scene.rs`. Adding a new component type
+/// is now a single line here instead of an edit to every one of those ladders.
+macro_rules! define_components {
+    ($($variant:ident => $ty:ty),+ $(,)?) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub enum ComponentType {
+            $($variant),+
+        }
+
+        $(
+            impl Component for $ty {
+                const COMPONENT_TYPE: ComponentType = ComponentType::$variant;
+            }
+        )+
+
+        impl ComponentType {
+            /// Inserts an empty storage vec for this type into `archetype`, if it doesn't
+            /// already have one.
+            fn init_storage(self, archetype: &mut Archetype) {
+                match self {
+                    $(ComponentType::$variant => {
+                        archetype
+                            .storage
+                            .entry(self)
+                            .or_insert_with(|| Box::new(Vec::<$ty>::new()) as ComponentVec);
+                    })+
+                }
+            }
+
+            /// Clears `storage`'s vec in place, assuming it holds this type's vec.
+            fn clear_storage(self, storage: &mut dyn Any) {
+                match self {
+                    $(ComponentType::$variant => {
+                        if let Some(vec) = storage.downcast_mut::<Vec<$ty>>() {
+                            vec.clear();
+                        }
+                    })+
+                }
+            }
+
+            /// Swap-removes `row` out of `storage`'s vec, assuming it holds this type's vec.
+            fn swap_remove_storage(self, storage: &mut dyn Any, row: usize) {
+                match self {
+                    $(ComponentType::$variant => {
+                        if let Some(vec) = storage.downcast_mut::<Vec<$ty>>() {
+                            if row < vec.len() {
+                                vec.swap_remove(row);
+                            }
+                        }
+                    })+
+                }
+            }
+
+            /// Clones the value at `row` in `src`'s storage for this type and appends it to
+            /// `dst`'s storage for the same type, allocating `dst`'s vec on first use. Used when
+            /// migrating an entity into a different archetype.
+            fn copy_storage_row(self, src: &Archetype, dst: &mut Archetype, row: usize) {
+                match self {
+                    $(ComponentType::$variant => {
+                        let Some(value) = src
+                            .storage
+                            .get(&self)
+                            .and_then(|s| s.downcast_ref::<Vec<$ty>>())
+                            .and_then(|v| v.get(row))
+                            .cloned()
+                        else {
+                            return;
+                        };
+                        dst.storage
+                            .entry(self)
+                            .or_insert_with(|| Box::new(Vec::<$ty>::new()) as ComponentVec)
+                            .downcast_mut::<Vec<$ty>>()
+                            .expect("archetype storage type mismatch for component")
+                            .push(value);
+                    })+
+                }
+            }
+
+            /// Clones the value at `row` and appends it to the end of the *same* archetype's
+            /// storage for this type. Used by `clone_entity`, where source and destination rows
+            /// live in the same archetype.
+            fn clone_storage_row(self, archetype: &mut Archetype, row: usize) {
+                match self {
+                    $(ComponentType::$variant => {
+                        let Some(value) = archetype
+                            .storage
+                            .get(&self)
+                            .and_then(|s| s.downcast_ref::<Vec<$ty>>())
+                            .and_then(|v| v.get(row))
+                            .cloned()
+                        else {
+                            return;
+                        };
+                        archetype
+                            .storage
+                            .get_mut(&self)
+                            .and_then(|s| s.downcast_mut::<Vec<$ty>>())
+                            .expect("archetype storage type mismatch for component")
+                            .push(value);
+                    })+
+                }
+            }
+        }
+    };
+}
+
+define_components! {
+    Transform => ffi::Transform,
+    Velocity => ffi::Velocity,
+    Material => Material,
+    Player => Player,
+    Obstacle => Obstacle,
+    Button => Button,
+    Slider => ui::Slider,
+    Physics => PhysicsBody,
+    Sprite => Sprite,
+    Light => ffi::Light,
+    ParticleEmitter => ParticleEmitter,
+    Particle => Particle,
 }
 
 #[cxx::bridge]
@@ -131,12 +329,43 @@ pub mod ffi {
         pub material_id: u32,
         pub texture_id: u32,
         pub transform: Transform,
+        pub color: Vec4,
+        /// Which layer of the texture atlas `uv_min`/`uv_max` are sampled from, from
+        /// `atlas::AtlasPacker`. `0` with a full `0,0..1,1` UV range means "unpacked" (the whole
+        /// bound `texture_id` surface, the behavior before atlas packing existed).
+        pub atlas_layer: u32,
+        pub uv_min: Vec2,
+        pub uv_max: Vec2,
+    }
+
+    /// One instanced draw call: every `RenderableObject` in `instance_start..instance_start +
+    /// instance_count` of `batch::batch_renderables`'s transform buffer shares this
+    /// `(mesh_id, material_id, texture_id)` key and can be submitted in a single draw.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub struct DrawBatch {
+        pub mesh_id: u32,
+        pub material_id: u32,
+        pub texture_id: u32,
+        pub instance_start: u32,
+        pub instance_count: u32,
     }
 
     #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
     pub enum AssetCommandType {
         LoadTexture,
         ReloadTexture,
+        LoadMesh,
+        ReloadMesh,
+    }
+
+    /// How the host should read the asset bytes for a command: `Path` means open and read
+    /// `AssetCommand::path` itself (the original, pre-`AssetPack` contract); `Bytes` means the
+    /// asset already lives decompressed in memory and the host should call
+    /// `get_asset_command_bytes` instead of touching the filesystem.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum AssetDeliveryMode {
+        Path,
+        Bytes,
     }
 
     #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -144,6 +373,15 @@ pub mod ffi {
         pub request_id: u32,
         pub type_: AssetCommandType,
         pub path: String,
+        pub delivery: AssetDeliveryMode,
+    }
+
+    /// One resolved node of a loaded glTF-style model: the sub-mesh handle to render and the
+    /// default `Transform` the node carried in the source file.
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct ModelNode {
+        pub mesh_handle: u32,
+        pub transform: Transform,
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
@@ -160,12 +398,34 @@ pub mod ffi {
         pub w: f32,
     }
 
+    /// Horizontal anchor a `TextCommand::position` is measured from, resolved against the text's
+    /// measured pixel width (see `font::FontMetrics::measure`) rather than the host guessing one.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum TextAlign {
+        Left,
+        Center,
+        Right,
+    }
+
     #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub struct TextCommand {
         pub text: String,
         pub position: Vec2,
         pub font_size: f32,
         pub color: Vec4,
+        pub alignment: TextAlign,
+        /// BMFont handle from `AssetServer::load_font`, or `0` for none (the host's built-in
+        /// fallback font), the same "0 means unset" convention as `texture_handle`/`mesh_handle`.
+        pub font_handle: u32,
+    }
+
+    /// A filled axis-aligned rectangle, emitted by `ui::ui_system` alongside a `TextCommand` so a
+    /// button/slider draws its own background instead of relying on the host to guess one.
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct RectCommand {
+        pub position: Vec2,
+        pub size: Vec2,
+        pub color: Vec4,
     }
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -174,6 +434,39 @@ pub mod ffi {
         pub bodyB: u64,
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum LightType {
+        Directional,
+        Point,
+        Spot,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum ShadowMode {
+        Off,
+        Hardware2x2,
+        Pcf,
+        Pcss,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct ShadowSettings {
+        pub enabled: bool,
+        pub mode: ShadowMode,
+        pub depth_bias: f32,
+        pub resolution: u32,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    pub struct Light {
+        pub light_type: LightType,
+        pub position: Vec3,
+        pub color: Vec4,
+        pub intensity: f32,
+        pub range: f32,
+        pub shadow: ShadowSettings,
+    }
+
     unsafe extern "C++" {
         include!("miyabi/bridge.h");
 
@@ -205,14 +498,30 @@ fn get_sprite_count() -> u32 {
     ffi::get_performance_test_sprite_count()
 }
 
+impl Default for ffi::TextCommand {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            position: ffi::Vec2::default(),
+            font_size: 16.0,
+            color: ffi::Vec4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 },
+            alignment: ffi::TextAlign::Left,
+            font_handle: 0,
+        }
+    }
+}
+
 // Main game state
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GameState {
+    ProfileSelect,
     Title,
     InGame,
     Pause,
     Result,
+    ReplayPlayback,
+    Cutscene,
     SpriteStressTest,
     PhysicsStressTest,
     UIStressTest,
@@ -224,6 +533,10 @@ pub struct SaveProgress {
     pub best_survival_sec: u32,
     pub total_play_count: u32,
     pub total_clear_count: u32,
+    pub last_seed: u32,
+    /// Flags set by `script::ScriptVm` cutscenes (e.g. `"intro_seen"`), so a one-time intro
+    /// script can `JUMP_IF` past itself on repeat plays.
+    pub script_flags: HashSet<String>,
 }
 
 impl Default for SaveProgress {
@@ -233,6 +546,8 @@ impl Default for SaveProgress {
             best_survival_sec: 0,
             total_play_count: 0,
             total_clear_count: 0,
+            last_seed: 0,
+            script_flags: HashSet::new(),
         }
     }
 }
@@ -243,6 +558,10 @@ pub struct SaveSettings {
     pub bgm_volume: f32,
     pub se_volume: f32,
     pub fullscreen: bool,
+    pub language: locale::Language,
+    /// Toggled by the `S` key; shows `push_debug_overlay_text`'s live archetype/asset-integrity
+    /// dump. Off by default so it never appears for a player who hasn't opted in.
+    pub debug_overlay: bool,
 }
 
 impl Default for SaveSettings {
@@ -252,6 +571,8 @@ impl Default for SaveSettings {
             bgm_volume: 0.8,
             se_volume: 0.8,
             fullscreen: false,
+            language: locale::Language::default(),
+            debug_overlay: false,
         }
     }
 }
@@ -298,6 +619,35 @@ pub struct AssetServer {
     pub next_request_id: u32,
     pub next_texture_handle: u32,
     pub next_asset_id: u64,
+
+    pub mesh_handle_map: HashMap<String, u32>,
+    pub mesh_path_map: HashMap<u32, String>,
+    pub mesh_asset_id_map: HashMap<u32, u64>,
+    pub asset_id_mesh_path_map: HashMap<u64, String>,
+    pub next_mesh_handle: u32,
+    /// Sub-mesh handles plus default `Transform` resolved for a multi-primitive/node model,
+    /// keyed by the model's primary mesh handle. Populated once the host finishes parsing the
+    /// glTF-style file and calls back through `notify_model_nodes_loaded`.
+    #[serde(skip)]
+    pub model_node_map: HashMap<u32, Vec<ffi::ModelNode>>,
+    /// Ordered override directories (e.g. `mods/foo`, `dlc`), checked highest-priority-first
+    /// ahead of the base install. `resolve` is the only thing that reads this; everything else
+    /// (`texture_handle_map`, `pending_requests`, ...) stays keyed on the logical asset path so
+    /// pushing/clearing roots and reimporting doesn't invalidate any existing handle.
+    #[serde(skip)]
+    pub roots: Vec<PathBuf>,
+    /// Mounted `.pkg` bundles, checked highest-priority-first ahead of `roots`. Like `roots`,
+    /// purely a resolution-time concern: handles, `pending_requests`, etc. stay keyed on the
+    /// logical asset path regardless of whether it ultimately comes from a pack or a loose file.
+    #[serde(skip)]
+    pub packs: Vec<assetpack::AssetPack>,
+    pub font_handle_map: HashMap<String, u32>,
+    pub next_font_handle: u32,
+    /// Parsed BMFont metrics, keyed by the handle returned from `load_font`. Unlike textures/
+    /// meshes, a `.fnt` descriptor is small, local, and needs no host round-trip to decode, so
+    /// it's parsed synchronously instead of going through `pending_requests`/`AssetCommand`.
+    #[serde(skip)]
+    pub fonts: HashMap<u32, font::FontMetrics>,
 }
 
 #[derive(Debug, Clone)]
@@ -318,7 +668,106 @@ impl AssetServer {
             next_request_id: 1,
             next_texture_handle: 1,
             next_asset_id: 1,
+
+            mesh_handle_map: HashMap::new(),
+            mesh_path_map: HashMap::new(),
+            mesh_asset_id_map: HashMap::new(),
+            asset_id_mesh_path_map: HashMap::new(),
+            next_mesh_handle: 1,
+            model_node_map: HashMap::new(),
+            roots: Vec::new(),
+            packs: Vec::new(),
+            font_handle_map: HashMap::new(),
+            next_font_handle: 1,
+            fonts: HashMap::new(),
+        }
+    }
+
+    /// Adds `root` as the lowest-priority (checked last) entry so far, unless it's already
+    /// registered. Called by `asset_server_push_root`; mod/DLC loaders push their overrides
+    /// before the base install's root, so the first call wins ties.
+    pub fn push_root(&mut self, root: &str) {
+        let root = PathBuf::from(root);
+        if !self.roots.iter().any(|existing| existing == &root) {
+            self.roots.push(root);
+        }
+    }
+
+    pub fn clear_roots(&mut self) {
+        self.roots.clear();
+    }
+
+    /// Resolves a logical asset path (e.g. `assets/player.png`) against `roots` in priority
+    /// order, returning the absolute path of the first `root/logical_path` that exists on disk.
+    /// Falls back to `logical_path` unchanged when no root has it (or none are registered), so
+    /// assets keep loading exactly as before this existed.
+    pub fn resolve(&self, logical_path: &str) -> String {
+        for root in &self.roots {
+            let candidate = root.join(logical_path);
+            if candidate.exists() {
+                return candidate
+                    .canonicalize()
+                    .unwrap_or(candidate)
+                    .to_string_lossy()
+                    .into_owned();
+            }
+        }
+        logical_path.to_string()
+    }
+
+    /// Parses a BMFont descriptor at `path` (resolved against `roots`/`packs` first) and caches
+    /// its metrics under a new handle, or returns the existing handle if already loaded. Unlike
+    /// `load_texture`, this has no async round-trip through the host: a `.fnt` file is small
+    /// local text, so it's parsed immediately and `None` means the load genuinely failed, not
+    /// "still pending".
+    pub fn load_font(&mut self, path: &str) -> Option<u32> {
+        if let Some(handle) = self.font_handle_map.get(path) {
+            return Some(*handle);
+        }
+
+        let parsed = match self.fetch_from_pack(path) {
+            Some(bytes) => font::FontMetrics::parse(&String::from_utf8_lossy(&bytes)),
+            None => font::FontMetrics::load(Path::new(&self.resolve(path))),
+        };
+        let metrics = match parsed {
+            Ok(metrics) => metrics,
+            Err(e) => {
+                eprintln!("[font] failed to load {path}: {e}");
+                return None;
+            }
+        };
+
+        let handle = self.next_font_handle;
+        self.next_font_handle += 1;
+        self.font_handle_map.insert(path.to_string(), handle);
+        self.fonts.insert(handle, metrics);
+        Some(handle)
+    }
+
+    pub fn font_metrics(&self, handle: u32) -> Option<&font::FontMetrics> {
+        self.fonts.get(&handle)
+    }
+
+    /// Opens `path` as an `AssetPack` and adds it as the next-lowest-priority pack, mirroring
+    /// `push_root`: mount mod/DLC packs before the base install's pack so the first call wins
+    /// ties.
+    pub fn mount_pack(&mut self, path: &str) -> Result<(), assetpack::AssetPackError> {
+        let pack = assetpack::AssetPack::open(Path::new(path))?;
+        self.packs.push(pack);
+        Ok(())
+    }
+
+    /// Decompresses `logical_path` from the first mounted pack that has it, in priority order.
+    /// A pack that claims the entry but fails to decompress it is treated the same as a pack
+    /// without the entry, so a single corrupt pack degrades to the next pack or a loose file
+    /// instead of failing the whole request.
+    pub fn fetch_from_pack(&self, logical_path: &str) -> Option<Vec<u8>> {
+        for pack in &self.packs {
+            if let Some(Ok(bytes)) = pack.fetch(logical_path) {
+                return Some(bytes);
+            }
         }
+        None
     }
 
     pub fn load_texture(&mut self, path: &str) -> u32 {
@@ -363,6 +812,70 @@ impl AssetServer {
         queued_count
     }
 
+    /// Loads a mesh/model file (e.g. a glTF scene). Returns the primary mesh handle immediately;
+    /// for files with multiple primitives/nodes, the resolved sub-mesh handles and per-node
+    /// transforms arrive later via `resolve_model_nodes` once the host finishes parsing.
+    pub fn load_model(&mut self, path: &str) -> u32 {
+        if let Some(handle) = self.mesh_handle_map.get(path) {
+            return *handle;
+        }
+
+        let handle = self.next_mesh_handle;
+        self.next_mesh_handle += 1;
+        let asset_id = self.next_asset_id;
+        self.next_asset_id += 1;
+
+        self.mesh_handle_map.insert(path.to_string(), handle);
+        self.mesh_path_map.insert(handle, path.to_string());
+        self.mesh_asset_id_map.insert(handle, asset_id);
+        self.asset_id_mesh_path_map.insert(asset_id, path.to_string());
+        self.enqueue_request(path, ffi::AssetCommandType::LoadMesh);
+
+        handle
+    }
+
+    pub fn reimport_model(&mut self, path: &str) -> bool {
+        if !self.mesh_handle_map.contains_key(path) {
+            return false;
+        }
+        if self.has_pending_request(path) {
+            return false;
+        }
+
+        self.enqueue_request(path, ffi::AssetCommandType::ReloadMesh);
+        true
+    }
+
+    pub fn reimport_all_models(&mut self) -> usize {
+        let paths: Vec<String> = self.mesh_handle_map.keys().cloned().collect();
+        let mut queued_count = 0usize;
+        for path in paths {
+            if self.reimport_model(&path) {
+                queued_count += 1;
+            }
+        }
+        queued_count
+    }
+
+    /// Records the sub-mesh handles and default transforms resolved for a model's nodes, keyed
+    /// by the model's primary mesh handle, so a single `load_model` call can later spawn several
+    /// renderable entities.
+    pub fn resolve_model_nodes(&mut self, model_handle: u32, nodes: Vec<ffi::ModelNode>) {
+        self.model_node_map.insert(model_handle, nodes);
+    }
+
+    pub fn model_nodes(&self, model_handle: u32) -> Option<&[ffi::ModelNode]> {
+        self.model_node_map.get(&model_handle).map(Vec::as_slice)
+    }
+
+    pub fn path_for_mesh_handle(&self, mesh_handle: u32) -> Option<&str> {
+        self.mesh_path_map.get(&mesh_handle).map(|path| path.as_str())
+    }
+
+    pub fn asset_id_for_mesh_handle(&self, mesh_handle: u32) -> Option<u64> {
+        self.mesh_asset_id_map.get(&mesh_handle).copied()
+    }
+
     fn enqueue_request(&mut self, path: &str, command_type: ffi::AssetCommandType) {
         let request_id = self.next_request_id;
         self.next_request_id += 1;
@@ -420,40 +933,48 @@ pub struct Material {
     pub texture_handle: u32,
 }
 
-impl Component for ffi::Transform {
-    const COMPONENT_TYPE: ComponentType = ComponentType::Transform;
-}
-impl Component for ffi::Velocity {
-    const COMPONENT_TYPE: ComponentType = ComponentType::Velocity;
-}
-impl Component for Material {
-    const COMPONENT_TYPE: ComponentType = ComponentType::Material;
-}
-
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Player;
-impl Component for Player {
-    const COMPONENT_TYPE: ComponentType = ComponentType::Player;
-}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Sprite;
-impl Component for Sprite {
-    const COMPONENT_TYPE: ComponentType = ComponentType::Sprite;
-}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Obstacle;
-impl Component for Obstacle {
-    const COMPONENT_TYPE: ComponentType = ComponentType::Obstacle;
-}
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct PhysicsBody {
     pub id: u64,
 }
-impl Component for PhysicsBody {
-    const COMPONENT_TYPE: ComponentType = ComponentType::Physics;
+
+/// Describes the shape of a particle burst: how long each particle lives, how far its velocity
+/// can randomly spread, how many spawn, the downward acceleration applied to them, and the color
+/// they lerp from/to over their lifetime. Carried by entities that spawn a burst when they're
+/// involved in a gameplay event (e.g. an `Obstacle` hitting the player, or being avoided), or built
+/// inline for events that aren't tied to a persistent entity. Read by `spawn_particle_burst`, which
+/// is the only thing that ever constructs `Particle` entities.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ParticleEmitter {
+    pub lifetime_sec: f32,
+    pub velocity_spread: f32,
+    pub count: u32,
+    pub gravity: f32,
+    pub start_color: ffi::Vec4,
+    pub end_color: ffi::Vec4,
+}
+
+/// A single short-lived particle spawned by `spawn_particle_burst`. `update_particles` advances
+/// `age_sec` by `FIXED_DT_SEC` each tick, falls it by `gravity`, and lerps `color` from
+/// `start_color` to `end_color` over `lifetime_sec`, at which point `despawn_expired_particles`
+/// removes it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Particle {
+    pub lifetime_sec: f32,
+    pub age_sec: f32,
+    pub gravity: f32,
+    pub start_color: ffi::Vec4,
+    pub end_color: ffi::Vec4,
+    pub color: ffi::Vec4,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -479,11 +1000,47 @@ impl Archetype {
     }
 }
 
+/// Tracks the high-water byte count of `Game`'s per-frame transient buffers (`renderables`,
+/// `text_commands`, `asset_commands`) across frames, logging whenever a frame sets a new one.
+/// This is bookkeeping only — it does not back or pool those `Vec`s itself; they still grow via
+/// ordinary `clear()`/`push()`, which already retains capacity across frames. Lives on
+/// `InternalWorld` because it's frame-over-frame bookkeeping about the live world's output, not
+/// per-frame state itself.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct FrameBufferStats {
+    renderables_high_water: usize,
+    text_commands_high_water: usize,
+    asset_commands_high_water: usize,
+}
+
+impl FrameBufferStats {
+    fn track(high_water: &mut usize, bytes: usize, label: &str) {
+        if bytes > *high_water {
+            *high_water = bytes;
+            eprintln!("[frame] {label} high-water mark: {bytes} bytes");
+        }
+    }
+
+    fn track_renderables(&mut self, bytes: usize) {
+        Self::track(&mut self.renderables_high_water, bytes, "renderables");
+    }
+
+    fn track_text_commands(&mut self, bytes: usize) {
+        Self::track(&mut self.text_commands_high_water, bytes, "text_commands");
+    }
+
+    fn track_asset_commands(&mut self, bytes: usize) {
+        Self::track(&mut self.asset_commands_high_water, bytes, "asset_commands");
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct InternalWorld {
     pub entities: HashMap<Entity, (usize, usize)>,
     pub archetypes: Vec<Archetype>,
     pub next_entity: u64,
+    #[serde(skip)]
+    pub frame_buffer_stats: FrameBufferStats,
 }
 
 impl InternalWorld {
@@ -492,6 +1049,7 @@ impl InternalWorld {
             entities: HashMap::new(),
             archetypes: Vec::new(),
             next_entity: 0,
+            frame_buffer_stats: FrameBufferStats::default(),
         }
     }
 
@@ -500,47 +1058,8 @@ impl InternalWorld {
             return idx;
         }
         let mut archetype = Archetype::new(types.clone());
-        if types.contains(&ComponentType::Transform) {
-            archetype.storage.insert(
-                ComponentType::Transform,
-                Box::new(Vec::<ffi::Transform>::new()),
-            );
-        }
-        if types.contains(&ComponentType::Velocity) {
-            archetype.storage.insert(
-                ComponentType::Velocity,
-                Box::new(Vec::<ffi::Velocity>::new()),
-            );
-        }
-        if types.contains(&ComponentType::Material) {
-            archetype
-                .storage
-                .insert(ComponentType::Material, Box::new(Vec::<Material>::new()));
-        }
-        if types.contains(&ComponentType::Player) {
-            archetype
-                .storage
-                .insert(ComponentType::Player, Box::new(Vec::<Player>::new()));
-        }
-        if types.contains(&ComponentType::Obstacle) {
-            archetype
-                .storage
-                .insert(ComponentType::Obstacle, Box::new(Vec::<Obstacle>::new()));
-        }
-        if types.contains(&ComponentType::Button) {
-            archetype
-                .storage
-                .insert(ComponentType::Button, Box::new(Vec::<Button>::new()));
-        }
-        if types.contains(&ComponentType::Physics) {
-            archetype
-                .storage
-                .insert(ComponentType::Physics, Box::new(Vec::<PhysicsBody>::new()));
-        }
-        if types.contains(&ComponentType::Sprite) {
-            archetype
-                .storage
-                .insert(ComponentType::Sprite, Box::new(Vec::<Sprite>::new()));
+        for component_type in &types {
+            component_type.init_storage(&mut archetype);
         }
         self.archetypes.push(archetype);
         self.archetypes.len() - 1
@@ -588,272 +1107,212 @@ impl InternalWorld {
         for archetype in self.archetypes.iter_mut() {
             if archetype.types.contains(&component_type) {
                 archetype.entity_count = 0;
-                for storage in archetype.storage.values_mut() {
-                    // This is a dynamic way of clearing a vector of any type.
-                    // It's a bit of a hack, but it works for now.
-                    // A proper implementation would have a trait with a clear method.
-                    if let Some(vec) = storage.downcast_mut::<Vec<ffi::Transform>>() {
-                        vec.clear();
-                    } else if let Some(vec) = storage.downcast_mut::<Vec<ffi::Velocity>>() {
-                        vec.clear();
-                    } else if let Some(vec) = storage.downcast_mut::<Vec<Material>>() {
-                        vec.clear();
-                    } else if let Some(vec) = storage.downcast_mut::<Vec<Player>>() {
-                        vec.clear();
-                    } else if let Some(vec) = storage.downcast_mut::<Vec<Obstacle>>() {
-                        vec.clear();
-                    } else if let Some(vec) = storage.downcast_mut::<Vec<Button>>() {
-                        vec.clear();
-                    } else if let Some(vec) = storage.downcast_mut::<Vec<PhysicsBody>>() {
-                        vec.clear();
-                    } else if let Some(vec) = storage.downcast_mut::<Vec<Sprite>>() {
-                        vec.clear();
-                    }
+                for (&stored_type, storage) in archetype.storage.iter_mut() {
+                    stored_type.clear_storage(storage.as_mut());
                 }
             }
         }
     }
-}
 
-pub trait ComponentBundle {
-    fn get_component_types() -> HashSet<ComponentType>
-    where
-        Self: Sized;
-    fn push_to_storage(self, archetype: &mut Archetype);
-}
-
-impl<T: Component> ComponentBundle for (T,) {
-    fn get_component_types() -> HashSet<ComponentType> {
-        let mut types = HashSet::new();
-        types.insert(T::COMPONENT_TYPE);
-        types
+    /// Removes `entity` from the world entirely: swap-removes its row out of every storage vec
+    /// in its archetype and re-points whichever entity occupied the vacated row's old position
+    /// (the one swapped in) at its new row. Returns false if `entity` was already gone.
+    pub fn despawn(&mut self, entity: Entity) -> bool {
+        let Some((archetype_idx, row)) = self.entities.remove(&entity) else {
+            return false;
+        };
+        self.remove_row(archetype_idx, row);
+        true
     }
 
-    fn push_to_storage(self, archetype: &mut Archetype) {
-        let vec = archetype
+    /// Adds `value` to `entity`, migrating it into the archetype for its new `ComponentType` set.
+    /// Every component the entity already had is copied across; returns false if the entity is
+    /// unknown or already carries a component of this type.
+    pub fn add_component<T: Component + Clone + 'static>(&mut self, entity: Entity, value: T) -> bool {
+        let Some(&(old_idx, _)) = self.entities.get(&entity) else {
+            return false;
+        };
+        let mut new_types = self.archetypes[old_idx].types.clone();
+        if !new_types.insert(T::COMPONENT_TYPE) {
+            return false;
+        }
+
+        self.migrate_entity(entity, new_types, None);
+
+        let (new_idx, _) = self.entities[&entity];
+        let archetype = &mut self.archetypes[new_idx];
+        archetype
             .storage
-            .get_mut(&T::COMPONENT_TYPE)
-            .unwrap()
+            .entry(T::COMPONENT_TYPE)
+            .or_insert_with(|| Box::new(Vec::<T>::new()) as ComponentVec)
             .downcast_mut::<Vec<T>>()
-            .unwrap();
-        vec.push(self.0);
+            .expect("archetype storage type mismatch for component")
+            .push(value);
+        true
     }
-}
 
-impl<T: Component, U: Component> ComponentBundle for (T, U) {
-    fn get_component_types() -> HashSet<ComponentType> {
-        let mut types = HashSet::new();
-        types.insert(T::COMPONENT_TYPE);
-        types.insert(U::COMPONENT_TYPE);
-        types
+    /// Removes the component of `component_type` from `entity`, migrating it into the archetype
+    /// for its remaining `ComponentType` set. Returns false if the entity is unknown or doesn't
+    /// carry this component.
+    pub fn remove_component(&mut self, entity: Entity, component_type: ComponentType) -> bool {
+        let Some(&(old_idx, _)) = self.entities.get(&entity) else {
+            return false;
+        };
+        let mut new_types = self.archetypes[old_idx].types.clone();
+        if !new_types.remove(&component_type) {
+            return false;
+        }
+
+        self.migrate_entity(entity, new_types, Some(component_type));
+        true
     }
 
-    fn push_to_storage(self, archetype: &mut Archetype) {
-        let vec_t = archetype
-            .storage
-            .get_mut(&T::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<T>>()
-            .unwrap();
-        vec_t.push(self.0);
-        let vec_u = archetype
-            .storage
-            .get_mut(&U::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<U>>()
-            .unwrap();
-        vec_u.push(self.1);
+    /// Duplicates `entity` into a fresh entity carrying a clone of every component it has,
+    /// appended as a new row in the same archetype. Returns `None` if `entity` is unknown.
+    pub fn clone_entity(&mut self, entity: Entity) -> Option<Entity> {
+        let &(archetype_idx, row) = self.entities.get(&entity)?;
+        let types: Vec<ComponentType> = self.archetypes[archetype_idx].types.iter().copied().collect();
+        let archetype = &mut self.archetypes[archetype_idx];
+        for component_type in types {
+            component_type.clone_storage_row(archetype, row);
+        }
+        let new_row = archetype.entity_count;
+        archetype.entity_count += 1;
+        let new_entity = Entity(self.next_entity);
+        self.next_entity += 1;
+        self.entities.insert(new_entity, (archetype_idx, new_row));
+        Some(new_entity)
     }
-}
 
-impl<T: Component, U: Component, V: Component> ComponentBundle for (T, U, V) {
-    fn get_component_types() -> HashSet<ComponentType> {
-        let mut types = HashSet::new();
-        types.insert(T::COMPONENT_TYPE);
-        types.insert(U::COMPONENT_TYPE);
-        types.insert(V::COMPONENT_TYPE);
-        types
+    /// Moves `entity` into the archetype for `new_types`, copying every component it has (except
+    /// `skip`, used by `remove_component` to drop the one being removed) across, then cleans up
+    /// its old row and repoints `self.entities` at the new one.
+    fn migrate_entity(
+        &mut self,
+        entity: Entity,
+        new_types: HashSet<ComponentType>,
+        skip: Option<ComponentType>,
+    ) -> usize {
+        let (old_idx, old_row) = self.entities[&entity];
+        let new_idx = self.get_or_create_archetype(new_types);
+
+        let copy_types: Vec<ComponentType> = self.archetypes[old_idx]
+            .types
+            .iter()
+            .copied()
+            .filter(|component_type| Some(*component_type) != skip)
+            .collect();
+
+        let new_row = {
+            let (old_archetype, new_archetype) =
+                borrow_two_archetypes_mut(&mut self.archetypes, old_idx, new_idx);
+            for component_type in copy_types {
+                component_type.copy_storage_row(old_archetype, new_archetype, old_row);
+            }
+            let row = new_archetype.entity_count;
+            new_archetype.entity_count += 1;
+            row
+        };
+
+        self.remove_row(old_idx, old_row);
+        self.entities.insert(entity, (new_idx, new_row));
+        new_row
     }
 
-    fn push_to_storage(self, archetype: &mut Archetype) {
-        let vec_t = archetype
-            .storage
-            .get_mut(&T::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<T>>()
-            .unwrap();
-        vec_t.push(self.0);
-        let vec_u = archetype
-            .storage
-            .get_mut(&U::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<U>>()
-            .unwrap();
-        vec_u.push(self.1);
-        let vec_v = archetype
-            .storage
-            .get_mut(&V::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<V>>()
-            .unwrap();
-        vec_v.push(self.2);
+    /// Swap-removes `row` out of `archetype_idx`'s storage and, if that row wasn't already the
+    /// last one, repoints whichever entity was swapped into it at its new (lower) row index.
+    fn remove_row(&mut self, archetype_idx: usize, row: usize) {
+        let last_row = self.archetypes[archetype_idx].entity_count - 1;
+        {
+            let archetype = &mut self.archetypes[archetype_idx];
+            swap_remove_row(archetype, row);
+            archetype.entity_count -= 1;
+        }
+
+        if row != last_row {
+            let moved_entity = self
+                .entities
+                .iter()
+                .find(|(_, &(idx, r))| idx == archetype_idx && r == last_row)
+                .map(|(&entity, _)| entity);
+            if let Some(moved_entity) = moved_entity {
+                self.entities.insert(moved_entity, (archetype_idx, row));
+            }
+        }
     }
 }
 
-impl<T: Component, U: Component, V: Component, W: Component> ComponentBundle for (T, U, V, W) {
-    fn get_component_types() -> HashSet<ComponentType> {
-        let mut types = HashSet::new();
-        types.insert(T::COMPONENT_TYPE);
-        types.insert(U::COMPONENT_TYPE);
-        types.insert(V::COMPONENT_TYPE);
-        types.insert(W::COMPONENT_TYPE);
-        types
+fn borrow_two_archetypes_mut(
+    archetypes: &mut [Archetype],
+    a: usize,
+    b: usize,
+) -> (&mut Archetype, &mut Archetype) {
+    assert_ne!(a, b, "cannot migrate an entity into its own archetype");
+    if a < b {
+        let (left, right) = archetypes.split_at_mut(b);
+        (&mut left[a], &mut right[0])
+    } else {
+        let (left, right) = archetypes.split_at_mut(a);
+        (&mut right[0], &mut left[b])
     }
+}
 
-    fn push_to_storage(self, archetype: &mut Archetype) {
-        let vec_t = archetype
-            .storage
-            .get_mut(&T::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<T>>()
-            .unwrap();
-        vec_t.push(self.0);
-        let vec_u = archetype
-            .storage
-            .get_mut(&U::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<U>>()
-            .unwrap();
-        vec_u.push(self.1);
-        let vec_v = archetype
-            .storage
-            .get_mut(&V::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<V>>()
-            .unwrap();
-        vec_v.push(self.2);
-        let vec_w = archetype
-            .storage
-            .get_mut(&W::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<W>>()
-            .unwrap();
-        vec_w.push(self.3);
+fn swap_remove_row(archetype: &mut Archetype, row: usize) {
+    for (&component_type, storage) in archetype.storage.iter_mut() {
+        component_type.swap_remove_storage(storage.as_mut(), row);
     }
 }
 
-impl<T: Component, U: Component, V: Component, W: Component, X: Component> ComponentBundle
-    for (T, U, V, W, X)
-{
-    fn get_component_types() -> HashSet<ComponentType> {
-        let mut types = HashSet::new();
-        types.insert(T::COMPONENT_TYPE);
-        types.insert(U::COMPONENT_TYPE);
-        types.insert(V::COMPONENT_TYPE);
-        types.insert(W::COMPONENT_TYPE);
-        types.insert(X::COMPONENT_TYPE);
-        types
-    }
+pub trait ComponentBundle {
+    fn get_component_types() -> HashSet<ComponentType>
+    where
+        Self: Sized;
+    fn push_to_storage(self, archetype: &mut Archetype);
+}
 
-    fn push_to_storage(self, archetype: &mut Archetype) {
-        let vec_t = archetype
-            .storage
-            .get_mut(&T::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<T>>()
-            .unwrap();
-        vec_t.push(self.0);
-        let vec_u = archetype
-            .storage
-            .get_mut(&U::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<U>>()
-            .unwrap();
-        vec_u.push(self.1);
-        let vec_v = archetype
-            .storage
-            .get_mut(&V::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<V>>()
-            .unwrap();
-        vec_v.push(self.2);
-        let vec_w = archetype
-            .storage
-            .get_mut(&W::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<W>>()
-            .unwrap();
-        vec_w.push(self.3);
-        let vec_x = archetype
-            .storage
-            .get_mut(&X::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<X>>()
-            .unwrap();
-        vec_x.push(self.4);
-    }
-}
-
-impl<T: Component, U: Component, V: Component, W: Component, X: Component, Y: Component>
-    ComponentBundle for (T, U, V, W, X, Y)
-{
-    fn get_component_types() -> HashSet<ComponentType> {
-        let mut types = HashSet::new();
-        types.insert(T::COMPONENT_TYPE);
-        types.insert(U::COMPONENT_TYPE);
-        types.insert(V::COMPONENT_TYPE);
-        types.insert(W::COMPONENT_TYPE);
-        types.insert(X::COMPONENT_TYPE);
-        types.insert(Y::COMPONENT_TYPE);
-        types
-    }
-
-    fn push_to_storage(self, archetype: &mut Archetype) {
-        let vec_t = archetype
-            .storage
-            .get_mut(&T::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<T>>()
-            .unwrap();
-        vec_t.push(self.0);
-        let vec_u = archetype
-            .storage
-            .get_mut(&U::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<U>>()
-            .unwrap();
-        vec_u.push(self.1);
-        let vec_v = archetype
-            .storage
-            .get_mut(&V::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<V>>()
-            .unwrap();
-        vec_v.push(self.2);
-        let vec_w = archetype
-            .storage
-            .get_mut(&W::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<W>>()
-            .unwrap();
-        vec_w.push(self.3);
-        let vec_x = archetype
-            .storage
-            .get_mut(&X::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<X>>()
-            .unwrap();
-        vec_x.push(self.4);
-        let vec_y = archetype
-            .storage
-            .get_mut(&Y::COMPONENT_TYPE)
-            .unwrap()
-            .downcast_mut::<Vec<Y>>()
-            .unwrap();
-        vec_y.push(self.5);
-    }
+/// Generates a `ComponentBundle` impl for a tuple of the given arity. Used in place of writing
+/// out `(T,)` through `(A, B, ..., L)` by hand, so `InternalWorld::spawn` supports bundles of up
+/// to 12 components for the same reason `define_components!` centralizes the `ComponentType`
+/// ladders: one macro invocation per arity instead of a hand-maintained copy of the same shape.
+macro_rules! impl_component_bundle {
+    ($($t:ident),+) => {
+        impl<$($t: Component),+> ComponentBundle for ($($t,)+) {
+            fn get_component_types() -> HashSet<ComponentType> {
+                let mut types = HashSet::new();
+                $(types.insert($t::COMPONENT_TYPE);)+
+                types
+            }
+
+            #[allow(non_snake_case)]
+            fn push_to_storage(self, archetype: &mut Archetype) {
+                let ($($t,)+) = self;
+                $(
+                    archetype
+                        .storage
+                        .get_mut(&$t::COMPONENT_TYPE)
+                        .unwrap()
+                        .downcast_mut::<Vec<$t>>()
+                        .unwrap()
+                        .push($t);
+                )+
+            }
+        }
+    };
 }
 
+impl_component_bundle!(A);
+impl_component_bundle!(A, B);
+impl_component_bundle!(A, B, C);
+impl_component_bundle!(A, B, C, D);
+impl_component_bundle!(A, B, C, D, E);
+impl_component_bundle!(A, B, C, D, E, F);
+impl_component_bundle!(A, B, C, D, E, F, G);
+impl_component_bundle!(A, B, C, D, E, F, G, H);
+impl_component_bundle!(A, B, C, D, E, F, G, H, I);
+impl_component_bundle!(A, B, C, D, E, F, G, H, I, J);
+impl_component_bundle!(A, B, C, D, E, F, G, H, I, J, K);
+impl_component_bundle!(A, B, C, D, E, F, G, H, I, J, K, L);
+
 // The main game object
 #[derive(Serialize, Deserialize)]
 pub struct Game {
@@ -865,14 +1324,52 @@ pub struct Game {
     #[serde(skip)]
     pub texture_map: HashMap<u32, u32>,
     #[serde(skip)]
+    pub mesh_map: HashMap<u32, u32>,
+    #[serde(skip)]
     pub input_state: ffi::InputState,
+    #[serde(skip)]
+    pub locale: locale::Locale,
+    /// Deterministic gameplay RNG, seeded once per run in `begin_run`. Unlike the other live
+    /// state above, this round-trips through `serialize_game`/`deserialize_game` (not skipped),
+    /// so resuming a serialized `Game` reproduces the exact same obstacle sequence rather than
+    /// silently reseeding.
+    pub rng: rng::XorShift,
+    #[serde(skip)]
+    pub recorder: Option<replay::Recorder>,
+    #[serde(skip)]
+    pub replay_player: Option<replay::Player>,
+    #[serde(skip)]
+    pub cutscene: Option<script::ScriptVm>,
+    /// Index of the profile slot chosen on `GameState::ProfileSelect`; resolves to `save_file_path`
+    /// via `profile_path`. Not persisted itself, since it's a property of this launch, not a slot.
+    #[serde(skip)]
+    pub active_slot: usize,
 
     #[serde(skip)]
-    pub renderables: Vec<ffi::RenderableObject>,
+    pub renderables: Vec<ffi::RenderableObject>,
+    /// One instanced draw per contiguous `(mesh_id, material_id, texture_id)` run of `renderables`,
+    /// rebuilt alongside it each frame by `batch::batch_renderables`. `instance_start`/
+    /// `instance_count` index into `batched_instances`, not `renderables` itself.
+    #[serde(skip)]
+    pub draw_batches: Vec<ffi::DrawBatch>,
+    #[serde(skip)]
+    pub batched_instances: Vec<ffi::Transform>,
+    #[serde(skip)]
+    pub lights: Vec<ffi::Light>,
     #[serde(skip)]
     pub asset_commands: Vec<ffi::AssetCommand>,
+    /// Decompressed bytes for in-flight bytes-backed `asset_commands`, keyed by `request_id`.
+    /// `get_asset_command_bytes` hands the host a view into an entry here; `notify_asset_loaded`
+    /// drops it once the host has read it, same lifecycle as `asset_server.pending_requests`.
+    #[serde(skip)]
+    pub asset_command_bytes: HashMap<u32, Vec<u8>>,
     #[serde(skip)]
     pub text_commands: Vec<ffi::TextCommand>,
+    /// Button/slider backgrounds `ui::ui_system` draws each frame, rebuilt from scratch at the
+    /// top of every call the same way `text_commands` gets cleared per-screen, so it never
+    /// outlives the widgets that produced it.
+    #[serde(skip)]
+    pub rect_commands: Vec<ffi::RectCommand>,
     #[serde(skip)]
     pub collision_events: Vec<ffi::CollisionEvent>,
 
@@ -891,11 +1388,29 @@ pub struct Game {
     pub obstacle_texture_handle: u32,
     #[serde(skip)]
     pub obstacle_spawn_accumulator_sec: f32,
+    /// Authored `stage::SpawnEvent`s for the current run, loaded once in `begin_run` and drained
+    /// in `update_in_game` as `survival_time_sec` reaches each event's `time_sec`. The random
+    /// spawner in `spawn_obstacle` takes back over once this drains empty.
+    #[serde(skip)]
+    pub stage_events: VecDeque<stage::SpawnEvent>,
+    /// Recomputed every tick by `update_camera`; purely a render-time view into the world, so it
+    /// isn't persisted.
+    #[serde(skip)]
+    pub camera: Camera,
+    /// Loaded from `GAMEPLAY_SCRIPT_ASSET_PATH` in `begin_run`, re-loaded by the pause-menu
+    /// "Reimport" shortcut. When present, `run_gameplay_script` gets first refusal on each tick's
+    /// difficulty/spawn/win-lose decisions; `update_in_game`'s hard-coded behavior is the fallback
+    /// used whenever no script is loaded.
+    #[cfg(feature = "scripting")]
+    #[serde(skip)]
+    pub gameplay_script: Option<gameplay_script::GameplayScript>,
     #[serde(skip)]
     pub esc_was_pressed: bool,
     #[serde(skip)]
     pub u_was_pressed: bool,
     #[serde(skip)]
+    pub s_was_pressed: bool,
+    #[serde(skip)]
     pub asset_integrity_tick: u32,
     #[serde(skip)]
     pub reported_missing_texture_handles: HashSet<u32>,
@@ -922,20 +1437,128 @@ const BASE_OBSTACLE_SPEED: f32 = 120.0;
 const MAX_OBSTACLES: usize = 80;
 const BASE_SPAWN_INTERVAL_SEC: f32 = 1.2;
 const MIN_SPAWN_INTERVAL_SEC: f32 = 0.25;
-const SAVE_FILE_REL_PATH: &str = "save/save_data.json";
 pub(crate) const SETTINGS_STEP: f32 = 0.1;
+/// Number of independent save profiles shown on `GameState::ProfileSelect`.
+pub(crate) const PROFILE_SLOT_COUNT: usize = 3;
 const BGM_TRACK_PATH: &str = "assets/test_sound.wav";
+/// Mesh/material IDs `build_renderables` tags particle entities with, distinct from the sprite
+/// obstacles/player use (`mesh_id: 1, material_id: 1`).
+const PARTICLE_MESH_ID: u32 = 2;
+const PARTICLE_MATERIAL_ID: u32 = 2;
+/// Extra margin (world units) `build_renderables` expands the camera's screen-sized viewport by
+/// before culling, so an entity doesn't pop out the instant it crosses the exact edge.
+const CULL_MARGIN: f32 = 64.0;
+/// Burst shape for an obstacle hitting the player: orange, fading fully transparent.
+const HIT_PARTICLE_EMITTER: ParticleEmitter = ParticleEmitter {
+    lifetime_sec: 0.35,
+    velocity_spread: 160.0,
+    count: 12,
+    gravity: 240.0,
+    start_color: ffi::Vec4 { x: 1.0, y: 0.6, z: 0.2, w: 1.0 },
+    end_color: ffi::Vec4 { x: 1.0, y: 0.6, z: 0.2, w: 0.0 },
+};
+/// Burst shape for an obstacle successfully avoided off the bottom of the screen: cyan, fading
+/// fully transparent. Not attached to any entity (avoids aren't tied to a specific obstacle's
+/// emitter the way hits are), so `update_obstacles_and_collisions` passes this constant directly.
+const AVOID_PARTICLE_EMITTER: ParticleEmitter = ParticleEmitter {
+    lifetime_sec: 0.3,
+    velocity_spread: 90.0,
+    count: 6,
+    gravity: 0.0,
+    start_color: ffi::Vec4 { x: 0.3, y: 0.9, z: 1.0, w: 1.0 },
+    end_color: ffi::Vec4 { x: 0.3, y: 0.9, z: 1.0, w: 0.0 },
+};
 const ASSET_INTEGRITY_CHECK_INTERVAL_FRAMES: u32 = 30;
+/// Hand-authored stage layout, see `stage::load_stage`. Missing or unparsable falls back to the
+/// purely random `spawn_obstacle` spawner, same graceful-degradation policy as `locale::Locale`.
+const STAGE_ASSET_PATH: &str = "assets/stages/stage1.png";
+/// Gameplay-tuning script path, see `gameplay_script` (only read when the `scripting` feature is
+/// enabled).
+#[cfg(feature = "scripting")]
+const GAMEPLAY_SCRIPT_ASSET_PATH: &str = "assets/scripts/gameplay.tsc";
+/// Title-screen button layout, a `scene::SceneData` of `Button`-only entities (see `ui_script`).
+/// Missing or unparsable falls back to the single hard-coded "start" button `setup_title_screen`
+/// has always spawned, the same graceful-degradation policy as `STAGE_ASSET_PATH`. Shipped at
+/// `assets/scripts/title_menu.ron` as the one menu currently authored this way; `setup_pause_menu`
+/// and `setup_result_menu` are still hard-coded `world.spawn` calls — migrating them needs
+/// `scene::ComponentValue` support for `Slider` and locale-aware text, not just `Button`.
+#[cfg(feature = "scripting")]
+const TITLE_MENU_ASSET_PATH: &str = "assets/scripts/title_menu.ron";
+
+/// Path to profile slot `slot`'s save file, `save/profile_{slot}.json`. Each slot is a fully
+/// independent `SaveData` (progress + settings), selected on `GameState::ProfileSelect`.
+fn profile_path(slot: usize) -> PathBuf {
+    PathBuf::from(format!("save/profile_{slot}.json"))
+}
+
+/// World bounds the camera clamps against. Deliberately equal to the viewport today: `stage.rs`'s
+/// PNG format has no spatial width/height of its own to derive a larger world from — its pixel
+/// columns are lanes normalized across `screen_width` (`stage::lane_to_x`) and its rows are
+/// scroll *time*, not world-space distance (`stage::ROW_TIME_SEC`). `update_camera`'s clamp
+/// therefore collapses to a fixed `(0, 0)` and `build_renderables`' offset subtraction is a no-op
+/// in every build that ships today. This stays infrastructure only — for a future stage format
+/// that actually describes a larger play field — and isn't wired to any content yet.
+const WORLD_WIDTH: f32 = SCREEN_WIDTH;
+const WORLD_HEIGHT: f32 = SCREEN_HEIGHT;
+
+// Keeps the doc comment above honest if one of these ever changes without the other.
+const _: () = assert!(WORLD_WIDTH >= SCREEN_WIDTH && WORLD_HEIGHT >= SCREEN_HEIGHT);
+
+/// Tracks the viewport's offset into the (potentially larger-than-screen) world. `target_x`/
+/// `target_y` are the point the camera wants to center on (the player's position); `x`/`y` are
+/// where it's actually clamped to once world bounds are taken into account.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Camera {
+    pub x: f32,
+    pub y: f32,
+    pub target_x: f32,
+    pub target_y: f32,
+}
+
+impl Camera {
+    /// Translates a world-space `Transform` into screen space by subtracting the camera offset.
+    /// HUD/`text_commands` don't go through this — they're built directly in screen space.
+    fn view(&self, transform: ffi::Transform) -> ffi::Transform {
+        ffi::Transform {
+            position: ffi::Vec3 {
+                x: transform.position.x - self.x,
+                y: transform.position.y - self.y,
+                z: transform.position.z,
+            },
+            ..transform
+        }
+    }
+}
+
+/// Linearly interpolates each channel of `from` toward `to` by `t` (expected in `0.0..=1.0`).
+fn lerp_vec4(from: ffi::Vec4, to: ffi::Vec4, t: f32) -> ffi::Vec4 {
+    ffi::Vec4 {
+        x: from.x + (to.x - from.x) * t,
+        y: from.y + (to.y - from.y) * t,
+        z: from.z + (to.z - from.z) * t,
+        w: from.w + (to.w - from.w) * t,
+    }
+}
 
 impl Game {
     pub fn new() -> Self {
-        let save_file_path = PathBuf::from(SAVE_FILE_REL_PATH);
-        let save_data = Self::load_save_data(&save_file_path);
+        let active_slot = 0;
+        let save_file_path = profile_path(active_slot);
+        let save_data = SaveData::default();
+        let locale = locale::Locale::load(save_data.settings.language);
+        let rng = rng::XorShift::new(save_data.progress.last_seed);
         let mut game = Game {
             world: InternalWorld::new(),
-            current_state: GameState::Title,
+            current_state: GameState::ProfileSelect,
             asset_server: AssetServer::new(),
             texture_map: HashMap::new(),
+            mesh_map: HashMap::new(),
+            locale,
+            rng,
+            recorder: None,
+            replay_player: None,
+            cutscene: None,
+            active_slot,
             input_state: ffi::InputState {
                 up: false,
                 down: false,
@@ -949,8 +1572,13 @@ impl Game {
                 mouse_clicked: false,
             },
             renderables: Vec::new(),
+            draw_batches: Vec::new(),
+            batched_instances: Vec::new(),
+            lights: Vec::new(),
             asset_commands: Vec::new(),
+            asset_command_bytes: HashMap::new(),
             text_commands: Vec::new(),
+            rect_commands: Vec::new(),
             collision_events: Vec::new(),
             hp: 3,
             survival_time_sec: 0.0,
@@ -963,8 +1591,13 @@ impl Game {
             player_texture_handle: 0,
             obstacle_texture_handle: 0,
             obstacle_spawn_accumulator_sec: 0.0,
+            stage_events: VecDeque::new(),
+            camera: Camera::default(),
+            #[cfg(feature = "scripting")]
+            gameplay_script: None,
             esc_was_pressed: false,
             u_was_pressed: false,
+            s_was_pressed: false,
             asset_integrity_tick: 0,
             reported_missing_texture_handles: HashSet::new(),
             reported_unresolved_texture_handles: HashSet::new(),
@@ -972,14 +1605,58 @@ impl Game {
             save_file_path,
         };
         // Setup the initial state
-        game.setup_title_screen();
+        game.setup_profile_select_screen();
         game.apply_runtime_audio_settings();
         game.apply_runtime_fullscreen_setting();
         game
     }
 
+    /// Reads `profile_path(slot)`'s `SaveProgress` without touching `self`, for the per-slot
+    /// summaries on `GameState::ProfileSelect`. Deliberately doesn't go through
+    /// `save::load_or_default`, since that backs up and clears any unparsable file it finds — not
+    /// appropriate for a screen that's just peeking at slots the player hasn't chosen yet.
+    fn peek_profile_progress(slot: usize) -> SaveProgress {
+        fs::read(profile_path(slot))
+            .ok()
+            .and_then(|raw| serde_json::from_slice::<save::SaveEnvelope<SaveData>>(&raw).ok())
+            .map(|envelope| envelope.payload.progress)
+            .unwrap_or_default()
+    }
+
+    /// Loads `slot`'s `SaveData` and switches to it: rebuilds `locale`/`rng` from its settings,
+    /// points `save_file_path` at it so `persist_save_data` writes back to the right file, then
+    /// moves on to the title screen.
+    pub(crate) fn select_slot(&mut self, slot: usize) {
+        self.active_slot = slot;
+        self.save_file_path = profile_path(slot);
+        self.save_data = Self::load_save_data(&self.save_file_path);
+        self.locale = locale::Locale::load(self.save_data.settings.language);
+        self.rng = rng::XorShift::new(self.save_data.progress.last_seed);
+        self.total_play_count = self.save_data.progress.total_play_count;
+        self.apply_runtime_audio_settings();
+        self.apply_runtime_fullscreen_setting();
+        self.setup_title_screen();
+    }
+
+    /// Moves a slot's save file to `.bak`, mirroring `save::load_or_default`'s corrupt-file
+    /// backup behavior, then refreshes the slot list so the deleted slot shows as empty again.
+    pub(crate) fn delete_slot(&mut self, slot: usize) {
+        let path = profile_path(slot);
+        if path.exists() {
+            let mut backup_path = path.clone();
+            backup_path.set_extension("json.bak");
+            if let Err(err) = fs::rename(&path, &backup_path) {
+                eprintln!(
+                    "[save] Failed to delete slot {slot} ({}): {err}",
+                    path.display()
+                );
+            }
+        }
+        self.setup_profile_select_screen();
+    }
+
     fn load_save_data(path: &Path) -> SaveData {
-        match save::load_or_default::<SaveData>(path) {
+        match save::load_or_default::<SaveData>(path, &save::MigrationRegistry::new()) {
             Ok(save::LoadState::Loaded(data)) => data.sanitized(),
             Ok(save::LoadState::Defaulted { data, backup_path }) => {
                 if let Some(path) = backup_path {
@@ -1029,7 +1706,11 @@ impl Game {
 
     fn apply_runtime_bgm_for_state(&self) {
         match self.current_state {
-            GameState::Title | GameState::InGame | GameState::Pause | GameState::Result => {
+            GameState::Title
+            | GameState::InGame
+            | GameState::Pause
+            | GameState::Result
+            | GameState::ReplayPlayback => {
                 ffi::play_bgm(BGM_TRACK_PATH, true);
             }
             _ => ffi::stop_bgm(),
@@ -1047,27 +1728,138 @@ impl Game {
         if queued > 0 {
             eprintln!("[asset] queued texture reimport count={queued}");
         }
+
+        #[cfg(feature = "scripting")]
+        self.reload_gameplay_script();
     }
 
-    fn collect_referenced_texture_handles(&self) -> HashSet<u32> {
-        let mut handles = HashSet::new();
-        for archetype in &self.world.archetypes {
-            if !archetype.types.contains(&ComponentType::Material) {
-                continue;
+    /// Loads (or re-loads) the gameplay-tuning script from `GAMEPLAY_SCRIPT_ASSET_PATH`, falling
+    /// back to `None` (the hard-coded behavior in `update_in_game`) when it's missing or fails to
+    /// parse, so an invalid edit never takes a run down. Called once per run in `begin_run` and
+    /// again whenever the player hits the pause-menu "Reimport" shortcut, for live-editing.
+    #[cfg(feature = "scripting")]
+    fn reload_gameplay_script(&mut self) {
+        match gameplay_script::GameplayScript::load(Path::new(GAMEPLAY_SCRIPT_ASSET_PATH)) {
+            Ok(script) => self.gameplay_script = Some(script),
+            Err(e) => {
+                eprintln!("gameplay script load failed, using hard-coded tuning: {e}");
+                self.gameplay_script = None;
             }
+        }
+    }
 
-            let Some(material_storage) = archetype.storage.get(&ComponentType::Material) else {
-                continue;
+    /// Evaluates the loaded `GameplayScript`'s rules in order, applying every matching
+    /// `SetDifficulty`/`SpawnObstacle` action and stopping at the first matching `Win`/`Lose`.
+    /// Returns `Some(is_clear)` when a rule decided the run is over, `None` otherwise; while a
+    /// script is loaded, `update_in_game` runs no hard-coded difficulty or win/lose check of its
+    /// own. Does nothing if no script is loaded.
+    #[cfg(feature = "scripting")]
+    fn run_gameplay_script(&mut self) -> Option<bool> {
+        let Some(script) = self.gameplay_script.clone() else {
+            return None;
+        };
+
+        for rule in script.rules() {
+            let matches = match rule.condition {
+                gameplay_script::Condition::Always => true,
+                gameplay_script::Condition::SurvivalAtLeast(sec) => self.survival_time_sec >= sec,
+                gameplay_script::Condition::HpAtMost(hp) => self.hp <= hp,
+                gameplay_script::Condition::AvoidAtLeast(count) => self.avoid_count >= count,
             };
-            let Some(materials) = material_storage.downcast_ref::<Vec<Material>>() else {
+            if !matches {
                 continue;
-            };
+            }
 
-            for material in materials {
-                handles.insert(material.texture_handle);
+            match rule.action {
+                gameplay_script::Action::Win => return Some(true),
+                gameplay_script::Action::Lose => return Some(false),
+                gameplay_script::Action::SetDifficulty(level) => self.difficulty_level = level,
+                gameplay_script::Action::SpawnObstacle { x, speed } => {
+                    if self.count_obstacles() < MAX_OBSTACLES {
+                        self.spawn_obstacle_at(x, speed);
+                    }
+                }
             }
         }
-        handles
+
+        None
+    }
+
+    fn handle_debug_overlay_shortcut(&mut self) {
+        let toggle_just_pressed = self.input_state.s_key && !self.s_was_pressed;
+        self.s_was_pressed = self.input_state.s_key;
+        if !toggle_just_pressed {
+            return;
+        }
+
+        self.save_data.settings.debug_overlay = !self.save_data.settings.debug_overlay;
+        self.persist_save_data("settings_changed");
+    }
+
+    fn count_entities_with(&self, component_type: ComponentType) -> usize {
+        self.world
+            .archetypes
+            .iter()
+            .filter(|arch| arch.types.contains(&component_type))
+            .map(|arch| arch.entity_count)
+            .sum()
+    }
+
+    /// Appends a live dump of `self.world.archetypes` plus the asset-integrity counters
+    /// `run_asset_integrity_check` already tracks, on top of whatever `current_state`'s own
+    /// `update_*` pushed this frame. Gated on `SaveData.settings.debug_overlay`, toggled by `S`.
+    fn push_debug_overlay_text(&mut self) {
+        if !self.save_data.settings.debug_overlay {
+            return;
+        }
+
+        let total_entities: usize = self.world.archetypes.iter().map(|a| a.entity_count).sum();
+        let mut lines = vec![format!(
+            "entities:{} obstacles:{} buttons:{}",
+            total_entities,
+            self.count_obstacles(),
+            self.count_entities_with(ComponentType::Button),
+        )];
+
+        for (i, archetype) in self.world.archetypes.iter().enumerate() {
+            lines.push(format!(
+                "archetype[{i}] count:{} types:{:?}",
+                archetype.entity_count, archetype.types
+            ));
+        }
+
+        let referenced = self.collect_referenced_texture_handles();
+        let loaded = referenced
+            .iter()
+            .filter(|handle| self.texture_map.contains_key(handle))
+            .count();
+        lines.push(format!(
+            "assets: referenced:{} loaded:{} pending:{} registry_consistent:{}",
+            referenced.len(),
+            loaded,
+            referenced.len() - loaded,
+            self.asset_server.is_registry_consistent(),
+        ));
+
+        for (i, line) in lines.into_iter().enumerate() {
+            self.text_commands.push(ffi::TextCommand {
+                text: line,
+                position: ffi::Vec2 {
+                    x: 12.0,
+                    y: SCREEN_HEIGHT - 20.0 - i as f32 * 16.0,
+                },
+                font_size: 14.0,
+                color: ffi::Vec4 { x: 0.4, y: 1.0, z: 0.4, w: 1.0 },
+                ..Default::default()
+            });
+        }
+    }
+
+    fn collect_referenced_texture_handles(&self) -> HashSet<u32> {
+        self.world
+            .query::<(Material,)>()
+            .map(|(_, (material,))| material.texture_handle)
+            .collect()
     }
 
     fn run_asset_integrity_check(&mut self) {
@@ -1126,8 +1918,22 @@ impl Game {
     }
 
     pub(crate) fn adjust_master_volume(&mut self, delta: f32) {
+        self.set_master_volume(self.save_data.settings.master_volume + delta);
+    }
+
+    pub(crate) fn adjust_bgm_volume(&mut self, delta: f32) {
+        self.set_bgm_volume(self.save_data.settings.bgm_volume + delta);
+    }
+
+    pub(crate) fn adjust_se_volume(&mut self, delta: f32) {
+        self.set_se_volume(self.save_data.settings.se_volume + delta);
+    }
+
+    /// Sets master volume to the absolute `value` (clamped to `0.0..=1.0`), for a continuously
+    /// dragged `ui::Slider` where `adjust_master_volume`'s relative delta doesn't apply.
+    pub(crate) fn set_master_volume(&mut self, value: f32) {
         let current = self.save_data.settings.master_volume;
-        let next = (current + delta).clamp(0.0, 1.0);
+        let next = value.clamp(0.0, 1.0);
         if (next - current).abs() > f32::EPSILON {
             self.save_data.settings.master_volume = next;
             self.apply_runtime_audio_settings();
@@ -1135,9 +1941,9 @@ impl Game {
         }
     }
 
-    pub(crate) fn adjust_bgm_volume(&mut self, delta: f32) {
+    pub(crate) fn set_bgm_volume(&mut self, value: f32) {
         let current = self.save_data.settings.bgm_volume;
-        let next = (current + delta).clamp(0.0, 1.0);
+        let next = value.clamp(0.0, 1.0);
         if (next - current).abs() > f32::EPSILON {
             self.save_data.settings.bgm_volume = next;
             self.apply_runtime_audio_settings();
@@ -1145,9 +1951,9 @@ impl Game {
         }
     }
 
-    pub(crate) fn adjust_se_volume(&mut self, delta: f32) {
+    pub(crate) fn set_se_volume(&mut self, value: f32) {
         let current = self.save_data.settings.se_volume;
-        let next = (current + delta).clamp(0.0, 1.0);
+        let next = value.clamp(0.0, 1.0);
         if (next - current).abs() > f32::EPSILON {
             self.save_data.settings.se_volume = next;
             self.apply_runtime_audio_settings();
@@ -1161,51 +1967,137 @@ impl Game {
         self.persist_save_data("settings_changed");
     }
 
+    /// Saves the just-finished run's recording to `save/replays/replay_{seed}.json`, if one was
+    /// taken (replay playback itself doesn't record, so this is a no-op after watching a replay).
+    pub(crate) fn save_current_replay(&mut self) {
+        let Some(recorder) = self.recorder.take() else {
+            return;
+        };
+        let replay = recorder.into_replay();
+        let name = format!("replay_{}", replay.seed);
+        match replay::save_replay(&replay, &name) {
+            Ok(path) => eprintln!("[replay] saved {}", path.display()),
+            Err(err) => eprintln!("[replay] failed to save replay: {err}"),
+        }
+    }
+
+    /// Loads and starts a `script::ScriptVm` cutscene from `path`, seeded with whatever flags
+    /// earlier cutscenes have already persisted into `SaveData.progress`.
+    pub(crate) fn start_cutscene(&mut self, path: &Path) {
+        match script::Script::load(path) {
+            Ok(parsed) => {
+                self.clear_menu_buttons();
+                self.text_commands.clear();
+                self.cutscene = Some(script::ScriptVm::new(
+                    parsed,
+                    self.save_data.progress.script_flags.clone(),
+                ));
+                self.current_state = GameState::Cutscene;
+            }
+            Err(err) => {
+                eprintln!("[script] failed to load cutscene {}: {err}", path.display());
+            }
+        }
+    }
+
+    fn update_cutscene(&mut self) {
+        let Some(mut vm) = self.cutscene.take() else {
+            self.setup_title_screen();
+            return;
+        };
+
+        self.text_commands.clear();
+        let mut texts = Vec::new();
+        let mut spawn_count = 0u32;
+        vm.tick(
+            |text| texts.push(text.to_string()),
+            |count| spawn_count += count,
+        );
+
+        for (i, text) in texts.into_iter().enumerate() {
+            self.text_commands.push(ffi::TextCommand {
+                text,
+                position: ffi::Vec2 {
+                    x: 80.0,
+                    y: 80.0 - i as f32 * 28.0,
+                },
+                font_size: 22.0,
+                color: ffi::Vec4 {
+                    x: 0.95,
+                    y: 0.95,
+                    z: 0.95,
+                    w: 1.0,
+                },
+                ..Default::default()
+            });
+        }
+        for _ in 0..spawn_count {
+            self.spawn_obstacle();
+        }
+
+        if vm.is_finished() {
+            self.save_data.progress.script_flags = vm.flags().clone();
+            self.persist_save_data("cutscene_finished");
+            self.setup_title_screen();
+            return;
+        }
+
+        self.cutscene = Some(vm);
+    }
+
+    /// Cycles to the next language, reloads the string table, and re-spawns the current menu's
+    /// buttons so their labels pick up the new translation immediately.
+    pub(crate) fn cycle_language(&mut self) {
+        self.save_data.settings.language = self.save_data.settings.language.next();
+        self.locale = locale::Locale::load(self.save_data.settings.language);
+        self.persist_save_data("settings_changed");
+
+        match self.current_state {
+            GameState::Title => self.setup_title_screen(),
+            GameState::Pause => self.setup_pause_menu(),
+            GameState::Result => self.setup_result_menu(),
+            _ => {}
+        }
+    }
+
     fn spawn_settings_buttons(&mut self, first_row_y: f32) {
         let row_step = 56.0;
-        let minus_x = 240.0;
-        let plus_x = 510.0;
-        let volume_button_w = 50.0;
+        let slider_x = 240.0;
+        let slider_w = 320.0;
+        let row_h = 40.0;
         let button_h = 40.0;
 
         let rows = [
             (
-                ui::ButtonAction::MasterVolumeDown,
-                ui::ButtonAction::MasterVolumeUp,
+                ui::SliderAction::MasterVolume,
+                self.save_data.settings.master_volume,
                 first_row_y,
             ),
             (
-                ui::ButtonAction::BgmVolumeDown,
-                ui::ButtonAction::BgmVolumeUp,
+                ui::SliderAction::BgmVolume,
+                self.save_data.settings.bgm_volume,
                 first_row_y - row_step,
             ),
             (
-                ui::ButtonAction::SeVolumeDown,
-                ui::ButtonAction::SeVolumeUp,
+                ui::SliderAction::SeVolume,
+                self.save_data.settings.se_volume,
                 first_row_y - row_step * 2.0,
             ),
         ];
 
-        for (down_action, up_action, y) in rows {
-            self.world.spawn((Button {
-                rect: ui::Rect {
-                    x: minus_x,
-                    y,
-                    width: volume_button_w,
-                    height: button_h,
-                },
-                text: "-".to_string(),
-                action: down_action,
-            },));
-            self.world.spawn((Button {
+        for (action, value, y) in rows {
+            self.world.spawn((ui::Slider {
                 rect: ui::Rect {
-                    x: plus_x,
+                    x: slider_x,
                     y,
-                    width: volume_button_w,
-                    height: button_h,
+                    width: slider_w,
+                    height: row_h,
                 },
-                text: "+".to_string(),
-                action: up_action,
+                min: 0.0,
+                max: 1.0,
+                value,
+                step: SETTINGS_STEP,
+                action,
             },));
         }
 
@@ -1216,8 +2108,24 @@ impl Game {
                 width: 300.0,
                 height: button_h,
             },
-            text: "Toggle Fullscreen".to_string(),
+            text: self.locale.get("settings.toggle_fullscreen"),
             action: ui::ButtonAction::ToggleFullscreen,
+            ..Default::default()
+        },));
+        self.world.spawn((Button {
+            rect: ui::Rect {
+                x: 580.0,
+                y: first_row_y - row_step * 3.0,
+                width: 180.0,
+                height: button_h,
+            },
+            text: format!(
+                "{}: {}",
+                self.locale.get("settings.language"),
+                self.locale.language.code()
+            ),
+            action: ui::ButtonAction::CycleLanguage,
+            ..Default::default()
         },));
     }
 
@@ -1226,14 +2134,17 @@ impl Game {
         let master_pct = (self.save_data.settings.master_volume * 100.0).round() as u32;
         let bgm_pct = (self.save_data.settings.bgm_volume * 100.0).round() as u32;
         let se_pct = (self.save_data.settings.se_volume * 100.0).round() as u32;
-        let fullscreen = if self.save_data.settings.fullscreen {
-            "ON"
+        let fullscreen_state = if self.save_data.settings.fullscreen {
+            self.locale.get("settings.fullscreen_on")
         } else {
-            "OFF"
+            self.locale.get("settings.fullscreen_off")
         };
 
         self.text_commands.push(ffi::TextCommand {
-            text: format!("Master Volume: {master_pct}%"),
+            text: self
+                .locale
+                .get("settings.master_volume")
+                .replace("{pct}", &master_pct.to_string()),
             position: ffi::Vec2 {
                 x: 305.0,
                 y: first_row_y + 12.0,
@@ -1245,9 +2156,13 @@ impl Game {
                 z: 0.9,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: format!("BGM Volume: {bgm_pct}%"),
+            text: self
+                .locale
+                .get("settings.bgm_volume")
+                .replace("{pct}", &bgm_pct.to_string()),
             position: ffi::Vec2 {
                 x: 305.0,
                 y: first_row_y - row_step + 12.0,
@@ -1259,9 +2174,13 @@ impl Game {
                 z: 0.9,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: format!("SE Volume: {se_pct}%"),
+            text: self
+                .locale
+                .get("settings.se_volume")
+                .replace("{pct}", &se_pct.to_string()),
             position: ffi::Vec2 {
                 x: 305.0,
                 y: first_row_y - row_step * 2.0 + 12.0,
@@ -1273,9 +2192,13 @@ impl Game {
                 z: 0.9,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: format!("Fullscreen: {fullscreen}"),
+            text: self
+                .locale
+                .get("settings.fullscreen")
+                .replace("{state}", &fullscreen_state),
             position: ffi::Vec2 {
                 x: 315.0,
                 y: first_row_y - row_step * 3.0 + 12.0,
@@ -1287,6 +2210,7 @@ impl Game {
                 z: 0.8,
                 w: 1.0,
             },
+            ..Default::default()
         });
     }
 
@@ -1306,24 +2230,45 @@ impl Game {
     }
 
     pub fn update(&mut self) {
+        self.track_frame_buffer_high_water();
         self.handle_reimport_shortcut();
+        self.handle_debug_overlay_shortcut();
         match self.current_state {
+            GameState::ProfileSelect => self.update_profile_select(),
             GameState::Title => self.update_main_menu(),
-            GameState::InGame => self.update_in_game(),
+            GameState::InGame | GameState::ReplayPlayback => self.update_in_game(),
             GameState::Pause => self.update_pause(),
             GameState::Result => self.update_result(),
+            GameState::Cutscene => self.update_cutscene(),
             GameState::SpriteStressTest => self.update_sprite_stress_test(),
             GameState::PhysicsStressTest => self.update_physics_stress_test(),
             GameState::UIStressTest => self.update_ui_stress_test(),
         }
         self.run_asset_integrity_check();
+        self.push_debug_overlay_text();
+    }
+
+    /// Feeds each per-frame transient buffer's size from the frame that just ended to
+    /// `world.frame_buffer_stats`, before the active screen's `update_*` clears and repopulates
+    /// them. The buffers already reuse their `Vec` capacity across frames (`clear()` never shrinks
+    /// it); this just surfaces the high-water mark so allocation spikes are diagnosable.
+    fn track_frame_buffer_high_water(&mut self) {
+        self.world
+            .frame_buffer_stats
+            .track_renderables(self.renderables.len() * std::mem::size_of::<ffi::RenderableObject>());
+        self.world
+            .frame_buffer_stats
+            .track_text_commands(self.text_commands.len() * std::mem::size_of::<ffi::TextCommand>());
+        self.world
+            .frame_buffer_stats
+            .track_asset_commands(self.asset_commands.len() * std::mem::size_of::<ffi::AssetCommand>());
     }
 
     fn update_main_menu(&mut self) {
         self.text_commands.clear();
         self.renderables.clear();
         self.text_commands.push(ffi::TextCommand {
-            text: "MIYABI Box Survival".to_string(),
+            text: self.locale.get("menu.title"),
             position: ffi::Vec2 { x: 255.0, y: 520.0 },
             font_size: 36.0,
             color: ffi::Vec4 {
@@ -1332,9 +2277,10 @@ impl Game {
                 z: 0.95,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: "Arrow Keys: Move / ESC: Pause".to_string(),
+            text: self.locale.get("menu.controls"),
             position: ffi::Vec2 { x: 235.0, y: 480.0 },
             font_size: 20.0,
             color: ffi::Vec4 {
@@ -1343,9 +2289,10 @@ impl Game {
                 z: 0.8,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: "U: Reimport Textures".to_string(),
+            text: self.locale.get("menu.reimport"),
             position: ffi::Vec2 { x: 290.0, y: 450.0 },
             font_size: 18.0,
             color: ffi::Vec4 {
@@ -1354,9 +2301,10 @@ impl Game {
                 z: 0.95,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: "Settings (auto-saved)".to_string(),
+            text: self.locale.get("menu.settings_header"),
             position: ffi::Vec2 { x: 285.0, y: 360.0 },
             font_size: 22.0,
             color: ffi::Vec4 {
@@ -1365,6 +2313,7 @@ impl Game {
                 z: 1.0,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.push_settings_text(300.0);
 
@@ -1375,6 +2324,8 @@ impl Game {
     pub(crate) fn clear_menu_buttons(&mut self) {
         self.world
             .clear_entities_of_component(ComponentType::Button);
+        self.world
+            .clear_entities_of_component(ComponentType::Slider);
     }
 
     fn clear_runtime_world(&mut self) {
@@ -1385,8 +2336,10 @@ impl Game {
             ComponentType::Player,
             ComponentType::Obstacle,
             ComponentType::Button,
+            ComponentType::Slider,
             ComponentType::Physics,
             ComponentType::Sprite,
+            ComponentType::Particle,
         ] {
             self.world.clear_entities_of_component(component_type);
         }
@@ -1395,12 +2348,97 @@ impl Game {
         self.asset_commands.clear();
     }
 
+    pub(crate) fn setup_profile_select_screen(&mut self) {
+        self.clear_runtime_world();
+        self.current_state = GameState::ProfileSelect;
+        self.esc_was_pressed = false;
+        self.apply_runtime_bgm_for_state();
+
+        let row_step = 90.0;
+        let first_row_y = 420.0;
+        for slot in 0..PROFILE_SLOT_COUNT {
+            let row_y = first_row_y - row_step * slot as f32;
+            self.world.spawn((Button {
+                rect: ui::Rect { x: 180.0, y: row_y, width: 160.0, height: 44.0 },
+                text: format!("{} {}", self.locale.get("profile.select"), slot + 1),
+                action: ui::ButtonAction::SelectSlot(slot),
+                ..Default::default()
+            },));
+            self.world.spawn((Button {
+                rect: ui::Rect { x: 360.0, y: row_y, width: 160.0, height: 44.0 },
+                text: self.locale.get("profile.delete"),
+                action: ui::ButtonAction::DeleteSlot(slot),
+                ..Default::default()
+            },));
+        }
+    }
+
+    fn update_profile_select(&mut self) {
+        self.text_commands.clear();
+        self.renderables.clear();
+        self.text_commands.push(ffi::TextCommand {
+            text: self.locale.get("profile.title"),
+            position: ffi::Vec2 { x: 260.0, y: 520.0 },
+            font_size: 32.0,
+            color: ffi::Vec4 { x: 0.95, y: 0.95, z: 0.95, w: 1.0 },
+            ..Default::default()
+        });
+
+        let row_step = 90.0;
+        let first_row_y = 420.0;
+        for slot in 0..PROFILE_SLOT_COUNT {
+            let row_y = first_row_y - row_step * slot as f32;
+            let progress = Self::peek_profile_progress(slot);
+            let summary = self
+                .locale
+                .get("profile.summary")
+                .replace("{score}", &progress.best_score.to_string())
+                .replace("{play}", &progress.total_play_count.to_string())
+                .replace("{clear}", &progress.total_clear_count.to_string());
+            self.text_commands.push(ffi::TextCommand {
+                text: summary,
+                position: ffi::Vec2 { x: 180.0, y: row_y + 48.0 },
+                font_size: 16.0,
+                color: ffi::Vec4 { x: 0.8, y: 0.85, z: 0.9, w: 1.0 },
+                ..Default::default()
+            });
+        }
+
+        ui::ui_system(self);
+    }
+
     pub(crate) fn setup_title_screen(&mut self) {
         self.clear_runtime_world();
         self.current_state = GameState::Title;
         self.esc_was_pressed = false;
         self.apply_runtime_bgm_for_state();
 
+        self.spawn_title_menu_buttons();
+        self.spawn_settings_buttons(300.0);
+    }
+
+    /// Spawns the title screen's scripted button layout from `TITLE_MENU_ASSET_PATH` (scripting
+    /// feature only), falling back to the single hard-coded "start" button on a missing or
+    /// unparsable file, the same graceful-degradation policy as `reload_gameplay_script`.
+    #[cfg(feature = "scripting")]
+    fn spawn_title_menu_buttons(&mut self) {
+        match scene::SceneData::load(Path::new(TITLE_MENU_ASSET_PATH)) {
+            Ok(menu) => {
+                self.world.spawn_scene(&menu);
+            }
+            Err(e) => {
+                eprintln!("title menu load failed, falling back to default button: {e}");
+                self.spawn_default_title_button();
+            }
+        }
+    }
+
+    #[cfg(not(feature = "scripting"))]
+    fn spawn_title_menu_buttons(&mut self) {
+        self.spawn_default_title_button();
+    }
+
+    fn spawn_default_title_button(&mut self) {
         self.world.spawn((Button {
             rect: ui::Rect {
                 x: 300.0,
@@ -1408,13 +2446,34 @@ impl Game {
                 width: 200.0,
                 height: 50.0,
             },
-            text: "Start Game".to_string(),
+            text: self.locale.get("menu.start"),
             action: ui::ButtonAction::StartGame,
+            ..Default::default()
         },));
-        self.spawn_settings_buttons(300.0);
     }
 
     pub(crate) fn start_new_run(&mut self) {
+        self.replay_player = None;
+        let seed = rand::thread_rng().gen::<u32>();
+        self.recorder = Some(replay::Recorder::start(seed));
+        self.begin_run(seed);
+    }
+
+    /// Starts a run seeded and driven by a previously recorded `Replay`, stepping `update_in_game`
+    /// off the `Player`'s frames instead of live `input_state`. No `Recorder` runs alongside it,
+    /// so watching a replay doesn't itself get recorded.
+    pub(crate) fn start_replay_playback(&mut self, replay: replay::Replay) {
+        let seed = replay.seed;
+        self.recorder = None;
+        self.replay_player = Some(replay::Player::new(replay));
+        self.begin_run(seed);
+        self.current_state = GameState::ReplayPlayback;
+        self.apply_runtime_bgm_for_state();
+    }
+
+    /// Shared setup for a fresh run: resets world/HUD state and seeds the RNG, whether the run is
+    /// freshly started (`start_new_run`) or replaying a saved seed (`start_replay_playback`).
+    fn begin_run(&mut self, seed: u32) {
         self.clear_runtime_world();
         self.current_state = GameState::InGame;
         self.esc_was_pressed = false;
@@ -1426,6 +2485,25 @@ impl Game {
         self.difficulty_level = 1;
         self.result_is_clear = false;
         self.obstacle_spawn_accumulator_sec = 0.0;
+
+        self.rng = rng::XorShift::new(seed);
+        self.save_data.progress.last_seed = seed;
+
+        self.stage_events = match stage::load_stage(
+            Path::new(STAGE_ASSET_PATH),
+            SCREEN_WIDTH,
+            BASE_OBSTACLE_SPEED,
+        ) {
+            Ok(events) => events.into(),
+            Err(e) => {
+                eprintln!("stage load failed, falling back to random spawns: {e}");
+                VecDeque::new()
+            }
+        };
+
+        #[cfg(feature = "scripting")]
+        self.reload_gameplay_script();
+
         self.save_data.progress.total_play_count =
             self.save_data.progress.total_play_count.saturating_add(1);
         self.total_play_count = self.save_data.progress.total_play_count;
@@ -1467,7 +2545,7 @@ impl Game {
             self.spawn_obstacle();
         }
 
-        ffi::play_sound("assets/test_sound.wav");
+        self.play_sound("assets/test_sound.wav");
     }
 
     fn setup_pause_menu(&mut self) {
@@ -1479,8 +2557,9 @@ impl Game {
                 width: 200.0,
                 height: 50.0,
             },
-            text: "Resume".to_string(),
+            text: self.locale.get("menu.resume"),
             action: ui::ButtonAction::ResumeGame,
+            ..Default::default()
         },));
         self.world.spawn((Button {
             rect: ui::Rect {
@@ -1489,8 +2568,9 @@ impl Game {
                 width: 200.0,
                 height: 50.0,
             },
-            text: "Back To Title".to_string(),
+            text: self.locale.get("menu.back_to_title"),
             action: ui::ButtonAction::BackToTitle,
+            ..Default::default()
         },));
         self.spawn_settings_buttons(170.0);
     }
@@ -1505,8 +2585,9 @@ impl Game {
                 width: 200.0,
                 height: 50.0,
             },
-            text: "Retry".to_string(),
+            text: self.locale.get("menu.retry"),
             action: ui::ButtonAction::RetryGame,
+            ..Default::default()
         },));
         self.world.spawn((Button {
             rect: ui::Rect {
@@ -1515,24 +2596,42 @@ impl Game {
                 width: 200.0,
                 height: 50.0,
             },
-            text: "Back To Title".to_string(),
+            text: self.locale.get("menu.back_to_title"),
             action: ui::ButtonAction::BackToTitle,
+            ..Default::default()
         },));
+
+        if self.recorder.is_some() {
+            self.world.spawn((Button {
+                rect: ui::Rect {
+                    x: 300.0,
+                    y: 110.0,
+                    width: 200.0,
+                    height: 50.0,
+                },
+                text: self.locale.get("result.save_replay"),
+                action: ui::ButtonAction::SaveReplay,
+                ..Default::default()
+            },));
+        }
     }
 
     fn spawn_obstacle(&mut self) {
+        let x = self.rng.next_range(20.0, SCREEN_WIDTH - 20.0);
+        self.spawn_obstacle_at(x, BASE_OBSTACLE_SPEED);
+    }
+
+    /// Spawns one obstacle at lane `x` falling at `speed`, shared by both the random spawner
+    /// (`spawn_obstacle`) and the authored-stage drain in `update_in_game`.
+    fn spawn_obstacle_at(&mut self, x: f32, speed: f32) {
         if self.obstacle_texture_handle == 0 {
             self.obstacle_texture_handle = self.asset_server.load_texture("assets/test.png");
         }
 
-        let mut rng = rand::thread_rng();
+        let y = SCREEN_HEIGHT + self.rng.next_range(20.0, 120.0);
         self.world.spawn((
             ffi::Transform {
-                position: ffi::Vec3 {
-                    x: rng.gen_range(20.0..(SCREEN_WIDTH - 20.0)),
-                    y: SCREEN_HEIGHT + rng.gen_range(20.0..120.0),
-                    z: 0.0,
-                },
+                position: ffi::Vec3 { x, y, z: 0.0 },
                 rotation: ffi::Vec3 {
                     x: 0.0,
                     y: 0.0,
@@ -1546,7 +2645,7 @@ impl Game {
             },
             ffi::Velocity {
                 x: 0.0,
-                y: -BASE_OBSTACLE_SPEED,
+                y: -speed,
                 z: 0.0,
             },
             Material {
@@ -1554,16 +2653,123 @@ impl Game {
             },
             Sprite,
             Obstacle,
+            HIT_PARTICLE_EMITTER,
         ));
     }
 
-    fn count_obstacles(&self) -> usize {
-        self.world
-            .archetypes
-            .iter()
-            .filter(|arch| arch.types.contains(&ComponentType::Obstacle))
-            .map(|arch| arch.entity_count)
-            .sum()
+    /// Spawns `emitter.count` short-lived `Particle` entities at `position`, each flung outward
+    /// at a random velocity within `emitter.velocity_spread` of the origin, falling under
+    /// `emitter.gravity`, and lerping from `emitter.start_color` to `emitter.end_color` over
+    /// `emitter.lifetime_sec`. Called once per obstacle-player hit (`HIT_PARTICLE_EMITTER`) or
+    /// successful avoid (`AVOID_PARTICLE_EMITTER`).
+    fn spawn_particle_burst(&mut self, position: ffi::Vec3, emitter: ParticleEmitter) {
+        for _ in 0..emitter.count {
+            let vx = self
+                .rng
+                .next_range(-emitter.velocity_spread, emitter.velocity_spread);
+            let vy = self
+                .rng
+                .next_range(-emitter.velocity_spread, emitter.velocity_spread);
+            self.world.spawn((
+                ffi::Transform {
+                    position,
+                    rotation: ffi::Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+                    scale: ffi::Vec3 { x: 4.0, y: 4.0, z: 1.0 },
+                },
+                ffi::Velocity { x: vx, y: vy, z: 0.0 },
+                Particle {
+                    lifetime_sec: emitter.lifetime_sec,
+                    age_sec: 0.0,
+                    gravity: emitter.gravity,
+                    start_color: emitter.start_color,
+                    end_color: emitter.end_color,
+                    color: emitter.start_color,
+                },
+            ));
+        }
+    }
+
+    /// Advances every `Particle`'s age, velocity (via `gravity`), and position by `FIXED_DT_SEC`,
+    /// lerps its `color` from `start_color` to `end_color` over `lifetime_sec`, then despawns
+    /// whichever ones have outlived their `lifetime_sec`.
+    fn update_particles(&mut self) {
+        for archetype in &mut self.world.archetypes {
+            if !(archetype.types.contains(&ComponentType::Particle)
+                && archetype.types.contains(&ComponentType::Transform)
+                && archetype.types.contains(&ComponentType::Velocity))
+            {
+                continue;
+            }
+
+            let mut transform_storage =
+                archetype.storage.remove(&ComponentType::Transform).unwrap();
+            let mut particle_storage =
+                archetype.storage.remove(&ComponentType::Particle).unwrap();
+            let mut velocity_storage =
+                archetype.storage.remove(&ComponentType::Velocity).unwrap();
+            let transforms = transform_storage
+                .downcast_mut::<Vec<ffi::Transform>>()
+                .unwrap();
+            let particles = particle_storage.downcast_mut::<Vec<Particle>>().unwrap();
+            let velocities = velocity_storage
+                .downcast_mut::<Vec<ffi::Velocity>>()
+                .unwrap();
+
+            for i in 0..archetype.entity_count {
+                velocities[i].y -= particles[i].gravity * FIXED_DT_SEC;
+                transforms[i].position.x += velocities[i].x * FIXED_DT_SEC;
+                transforms[i].position.y += velocities[i].y * FIXED_DT_SEC;
+                particles[i].age_sec += FIXED_DT_SEC;
+
+                let t = (particles[i].age_sec / particles[i].lifetime_sec).clamp(0.0, 1.0);
+                particles[i].color = lerp_vec4(particles[i].start_color, particles[i].end_color, t);
+            }
+
+            archetype
+                .storage
+                .insert(ComponentType::Transform, transform_storage);
+            archetype
+                .storage
+                .insert(ComponentType::Particle, particle_storage);
+            archetype
+                .storage
+                .insert(ComponentType::Velocity, velocity_storage);
+        }
+
+        self.despawn_expired_particles();
+    }
+
+    /// Scans every entity for an expired `Particle` and removes it. A plain linear scan over
+    /// `self.world.entities`, in the same spirit as `InternalWorld::clear_entities_of_component`
+    /// and `remove_row`'s swapped-entity lookup — simple, and particle counts stay small enough
+    /// for it not to matter.
+    fn despawn_expired_particles(&mut self) {
+        let mut expired = Vec::new();
+        for (&entity, &(archetype_idx, row)) in &self.world.entities {
+            let archetype = &self.world.archetypes[archetype_idx];
+            let Some(particles) = archetype
+                .storage
+                .get(&ComponentType::Particle)
+                .and_then(|s| s.downcast_ref::<Vec<Particle>>())
+            else {
+                continue;
+            };
+            if particles
+                .get(row)
+                .map(|p| p.age_sec >= p.lifetime_sec)
+                .unwrap_or(false)
+            {
+                expired.push(entity);
+            }
+        }
+
+        for entity in expired {
+            self.world.despawn(entity);
+        }
+    }
+
+    fn count_obstacles(&self) -> usize {
+        self.count_entities_with(ComponentType::Obstacle)
     }
 
     fn current_spawn_interval_sec(&self) -> f32 {
@@ -1649,11 +2855,35 @@ impl Game {
         player_bounds
     }
 
+    /// Follows the player's center (falling back to the world's own center if there's no player,
+    /// e.g. during a stress-test state) and clamps to `WORLD_WIDTH`/`WORLD_HEIGHT`: centered when
+    /// the world is narrower than the viewport, edge-clamped otherwise. `build_renderables`
+    /// subtracts the result from every `Transform.position` before emitting renderables.
+    fn update_camera(&mut self, player_bounds: Option<(f32, f32, f32, f32)>) {
+        let (target_x, target_y) = match player_bounds {
+            Some((left, bottom, right, top)) => ((left + right) * 0.5, (bottom + top) * 0.5),
+            None => (WORLD_WIDTH * 0.5, WORLD_HEIGHT * 0.5),
+        };
+        self.camera.target_x = target_x;
+        self.camera.target_y = target_y;
+
+        self.camera.x = if WORLD_WIDTH <= SCREEN_WIDTH {
+            (WORLD_WIDTH - SCREEN_WIDTH) * 0.5
+        } else {
+            (target_x - SCREEN_WIDTH * 0.5).clamp(0.0, WORLD_WIDTH - SCREEN_WIDTH)
+        };
+        self.camera.y = if WORLD_HEIGHT <= SCREEN_HEIGHT {
+            (WORLD_HEIGHT - SCREEN_HEIGHT) * 0.5
+        } else {
+            (target_y - SCREEN_HEIGHT * 0.5).clamp(0.0, WORLD_HEIGHT - SCREEN_HEIGHT)
+        };
+    }
+
     fn update_obstacles_and_collisions(&mut self, player_bounds: Option<(f32, f32, f32, f32)>) {
         let obstacle_speed =
             BASE_OBSTACLE_SPEED + (self.difficulty_level.saturating_sub(1) as f32) * 30.0;
-        let mut rng = rand::thread_rng();
         let mut hit_detected = false;
+        let mut particle_bursts: Vec<(ffi::Vec3, ParticleEmitter)> = Vec::new();
 
         for archetype in &mut self.world.archetypes {
             if !(archetype.types.contains(&ComponentType::Obstacle)
@@ -1672,14 +2902,20 @@ impl Game {
             let velocities = velocity_storage
                 .downcast_mut::<Vec<ffi::Velocity>>()
                 .unwrap();
+            let emitter_storage = archetype
+                .storage
+                .get(&ComponentType::ParticleEmitter)
+                .and_then(|s| s.downcast_ref::<Vec<ParticleEmitter>>());
 
             for i in 0..archetype.entity_count {
                 velocities[i].y = -obstacle_speed;
                 transforms[i].position.y += velocities[i].y * FIXED_DT_SEC;
 
-                if transforms[i].position.y < -OBSTACLE_SIZE {
-                    transforms[i].position.y = SCREEN_HEIGHT + rng.gen_range(20.0..120.0);
-                    transforms[i].position.x = rng.gen_range(20.0..(SCREEN_WIDTH - 20.0));
+                if transforms[i].position.y < self.camera.y - OBSTACLE_SIZE {
+                    particle_bursts.push((transforms[i].position, AVOID_PARTICLE_EMITTER));
+                    transforms[i].position.y = SCREEN_HEIGHT + self.rng.next_range(20.0, 120.0);
+                    transforms[i].position.x =
+                        self.rng.next_range(20.0, SCREEN_WIDTH - 20.0);
                     self.avoid_count = self.avoid_count.saturating_add(1);
                 }
 
@@ -1693,8 +2929,13 @@ impl Game {
                     let overlaps = pl < oright && pr > ol && pb < ot && pt > ob;
                     if overlaps {
                         self.hp -= 1;
-                        transforms[i].position.y = SCREEN_HEIGHT + rng.gen_range(20.0..120.0);
-                        transforms[i].position.x = rng.gen_range(20.0..(SCREEN_WIDTH - 20.0));
+                        if let Some(emitter) = emitter_storage.and_then(|v| v.get(i)).copied() {
+                            particle_bursts.push((transforms[i].position, emitter));
+                        }
+                        transforms[i].position.y =
+                            SCREEN_HEIGHT + self.rng.next_range(20.0, 120.0);
+                        transforms[i].position.x =
+                            self.rng.next_range(20.0, SCREEN_WIDTH - 20.0);
                         hit_detected = true;
                     }
                 }
@@ -1708,17 +2949,25 @@ impl Game {
                 .insert(ComponentType::Velocity, velocity_storage);
         }
 
+        for (position, emitter) in particle_bursts {
+            self.spawn_particle_burst(position, emitter);
+        }
+
         if hit_detected {
-            ffi::play_sound("assets/test_sound.wav");
+            self.play_sound("assets/test_sound.wav");
         }
     }
 
     fn push_hud_text(&mut self) {
+        let text = self
+            .locale
+            .get("hud.status")
+            .replace("{hp}", &self.hp.to_string())
+            .replace("{time}", &format!("{:.1}", self.survival_time_sec))
+            .replace("{score}", &self.score.to_string())
+            .replace("{lv}", &self.difficulty_level.to_string());
         self.text_commands.push(ffi::TextCommand {
-            text: format!(
-                "HP:{}  Time:{:.1}s  Score:{}  Lv:{}",
-                self.hp, self.survival_time_sec, self.score, self.difficulty_level
-            ),
+            text,
             position: ffi::Vec2 { x: 16.0, y: 570.0 },
             font_size: 20.0,
             color: ffi::Vec4 {
@@ -1727,13 +2976,14 @@ impl Game {
                 z: 1.0,
                 w: 1.0,
             },
+            ..Default::default()
         });
     }
 
     fn update_pause(&mut self) {
         self.text_commands.clear();
         self.text_commands.push(ffi::TextCommand {
-            text: "PAUSED".to_string(),
+            text: self.locale.get("pause.title"),
             position: ffi::Vec2 { x: 340.0, y: 420.0 },
             font_size: 36.0,
             color: ffi::Vec4 {
@@ -1742,9 +2992,10 @@ impl Game {
                 z: 0.2,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: "U: Reimport Textures".to_string(),
+            text: self.locale.get("menu.reimport"),
             position: ffi::Vec2 { x: 290.0, y: 390.0 },
             font_size: 18.0,
             color: ffi::Vec4 {
@@ -1753,9 +3004,10 @@ impl Game {
                 z: 0.95,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: "Settings (auto-saved)".to_string(),
+            text: self.locale.get("menu.settings_header"),
             position: ffi::Vec2 { x: 285.0, y: 230.0 },
             font_size: 20.0,
             color: ffi::Vec4 {
@@ -1764,6 +3016,7 @@ impl Game {
                 z: 1.0,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.push_settings_text(170.0);
 
@@ -1783,12 +3036,12 @@ impl Game {
         self.renderables.clear();
 
         let headline = if self.result_is_clear {
-            "CLEAR"
+            self.locale.get("result.clear")
         } else {
-            "GAME OVER"
+            self.locale.get("result.game_over")
         };
         self.text_commands.push(ffi::TextCommand {
-            text: headline.to_string(),
+            text: headline,
             position: ffi::Vec2 { x: 300.0, y: 440.0 },
             font_size: 42.0,
             color: ffi::Vec4 {
@@ -1797,9 +3050,13 @@ impl Game {
                 z: 0.2,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: format!("Score: {}", self.score),
+            text: self
+                .locale
+                .get("result.score")
+                .replace("{score}", &self.score.to_string()),
             position: ffi::Vec2 { x: 300.0, y: 390.0 },
             font_size: 26.0,
             color: ffi::Vec4 {
@@ -1808,9 +3065,13 @@ impl Game {
                 z: 0.95,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: format!("Survival: {:.1} sec", self.survival_time_sec),
+            text: self
+                .locale
+                .get("result.survival")
+                .replace("{sec}", &format!("{:.1}", self.survival_time_sec)),
             position: ffi::Vec2 { x: 300.0, y: 360.0 },
             font_size: 22.0,
             color: ffi::Vec4 {
@@ -1819,9 +3080,13 @@ impl Game {
                 z: 0.85,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: format!("High Score: {}", self.save_data.progress.best_score),
+            text: self
+                .locale
+                .get("result.high_score")
+                .replace("{score}", &self.save_data.progress.best_score.to_string()),
             position: ffi::Vec2 { x: 300.0, y: 330.0 },
             font_size: 20.0,
             color: ffi::Vec4 {
@@ -1830,11 +3095,12 @@ impl Game {
                 z: 0.8,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: format!(
-                "Best Survival: {} sec",
-                self.save_data.progress.best_survival_sec
+            text: self.locale.get("result.best_survival").replace(
+                "{sec}",
+                &self.save_data.progress.best_survival_sec.to_string(),
             ),
             position: ffi::Vec2 { x: 300.0, y: 305.0 },
             font_size: 20.0,
@@ -1844,12 +3110,17 @@ impl Game {
                 z: 0.95,
                 w: 1.0,
             },
+            ..Default::default()
         });
         self.text_commands.push(ffi::TextCommand {
-            text: format!(
-                "Play:{}  Clear:{}",
-                self.save_data.progress.total_play_count, self.save_data.progress.total_clear_count
-            ),
+            text: self
+                .locale
+                .get("result.play_clear")
+                .replace("{play}", &self.save_data.progress.total_play_count.to_string())
+                .replace(
+                    "{clear}",
+                    &self.save_data.progress.total_clear_count.to_string(),
+                ),
             position: ffi::Vec2 { x: 300.0, y: 280.0 },
             font_size: 18.0,
             color: ffi::Vec4 {
@@ -1858,6 +3129,17 @@ impl Game {
                 z: 0.75,
                 w: 1.0,
             },
+            ..Default::default()
+        });
+        self.text_commands.push(ffi::TextCommand {
+            text: self
+                .locale
+                .get("result.seed")
+                .replace("{seed}", &self.save_data.progress.last_seed.to_string()),
+            position: ffi::Vec2 { x: 300.0, y: 255.0 },
+            font_size: 16.0,
+            color: ffi::Vec4 { x: 0.65, y: 0.65, z: 0.65, w: 1.0 },
+            ..Default::default()
         });
 
         ui::ui_system(self);
@@ -1869,7 +3151,6 @@ impl Game {
         self.world
             .clear_entities_of_component(ComponentType::Physics);
 
-        let mut rng = rand::thread_rng();
         let player_texture = self.asset_server.load_texture("assets/player.png");
 
         #[cfg(feature = "performance_test")]
@@ -1881,8 +3162,8 @@ impl Game {
             self.world.spawn((
                 ffi::Transform {
                     position: ffi::Vec3 {
-                        x: rng.gen_range(0.0..800.0),
-                        y: rng.gen_range(0.0..600.0),
+                        x: self.rng.next_range(0.0, 800.0),
+                        y: self.rng.next_range(0.0, 600.0),
                         z: 0.0,
                     },
                     rotation: ffi::Vec3 {
@@ -1908,6 +3189,7 @@ impl Game {
         self.text_commands.clear();
         self.process_asset_server();
         self.build_renderables();
+        self.build_lights();
     }
 
     fn poll_physics_events(&mut self) {
@@ -2021,6 +3303,7 @@ impl Game {
         self.sync_physics_to_render();
         self.process_asset_server();
         self.build_renderables();
+        self.build_lights();
     }
 
     fn setup_ui_stress_test(&mut self) {
@@ -2056,6 +3339,7 @@ impl Game {
                         z: 0.1,
                         w: 1.0,
                     },
+                    ..Default::default()
                 });
             }
         }
@@ -2068,6 +3352,19 @@ impl Game {
     fn update_in_game(&mut self) {
         self.text_commands.clear();
 
+        if let Some(player) = self.replay_player.as_mut() {
+            match player.next_frame() {
+                Some(frame) => self.input_state = frame,
+                None => {
+                    self.replay_player = None;
+                    self.setup_title_screen();
+                    return;
+                }
+            }
+        } else if let Some(recorder) = self.recorder.as_mut() {
+            recorder.record(self.input_state);
+        }
+
         let esc_just_pressed = self.input_state.esc_key && !self.esc_was_pressed;
         self.esc_was_pressed = self.input_state.esc_key;
         if esc_just_pressed {
@@ -2077,42 +3374,76 @@ impl Game {
         }
 
         let player_bounds = self.update_player_and_get_bounds();
-
-        self.obstacle_spawn_accumulator_sec += FIXED_DT_SEC;
-        let spawn_interval = self.current_spawn_interval_sec();
-        while self.obstacle_spawn_accumulator_sec >= spawn_interval {
-            if self.count_obstacles() < MAX_OBSTACLES {
-                self.spawn_obstacle();
+        self.update_camera(player_bounds);
+
+        if self.stage_events.is_empty() {
+            self.obstacle_spawn_accumulator_sec += FIXED_DT_SEC;
+            let spawn_interval = self.current_spawn_interval_sec();
+            while self.obstacle_spawn_accumulator_sec >= spawn_interval {
+                if self.count_obstacles() < MAX_OBSTACLES {
+                    self.spawn_obstacle();
+                }
+                self.obstacle_spawn_accumulator_sec -= spawn_interval;
+            }
+        } else {
+            while let Some(event) = self.stage_events.front() {
+                if event.time_sec > self.survival_time_sec {
+                    break;
+                }
+                let event = self.stage_events.pop_front().unwrap();
+                if event.kind == stage::SpawnKind::Obstacle && self.count_obstacles() < MAX_OBSTACLES {
+                    self.spawn_obstacle_at(event.x, event.speed);
+                }
             }
-            self.obstacle_spawn_accumulator_sec -= spawn_interval;
         }
 
         self.update_obstacles_and_collisions(player_bounds);
+        self.update_particles();
         self.survival_time_sec += FIXED_DT_SEC;
-        self.difficulty_level = (self.survival_time_sec / 60.0).floor() as u32 + 1;
         self.score = (self.survival_time_sec as u32)
             .saturating_mul(10)
             .saturating_add(self.avoid_count.saturating_mul(100));
 
-        if self.hp <= 0 {
-            self.result_is_clear = false;
-            self.apply_result_to_progress_and_persist();
-            self.current_state = GameState::Result;
-            self.setup_result_menu();
-            ffi::play_sound("assets/test_sound.wav");
-            return;
-        }
-        if self.survival_time_sec >= 1800.0 {
-            self.result_is_clear = true;
-            self.apply_result_to_progress_and_persist();
-            self.current_state = GameState::Result;
-            self.setup_result_menu();
-            ffi::play_sound("assets/test_sound.wav");
-            return;
+        #[cfg(feature = "scripting")]
+        let script_decided = self.gameplay_script.is_some().then(|| self.run_gameplay_script());
+        #[cfg(not(feature = "scripting"))]
+        let script_decided: Option<Option<bool>> = None;
+
+        // A loaded script owns difficulty/win/lose entirely (it only has `Game`'s primitives to
+        // work with, per its own rules); the hard-coded curve below is only the no-script default.
+        if let Some(result) = script_decided {
+            if let Some(is_clear) = result {
+                self.result_is_clear = is_clear;
+                self.apply_result_to_progress_and_persist();
+                self.current_state = GameState::Result;
+                self.setup_result_menu();
+                self.play_sound("assets/test_sound.wav");
+                return;
+            }
+        } else {
+            self.difficulty_level = (self.survival_time_sec / 60.0).floor() as u32 + 1;
+
+            if self.hp <= 0 {
+                self.result_is_clear = false;
+                self.apply_result_to_progress_and_persist();
+                self.current_state = GameState::Result;
+                self.setup_result_menu();
+                self.play_sound("assets/test_sound.wav");
+                return;
+            }
+            if self.survival_time_sec >= 1800.0 {
+                self.result_is_clear = true;
+                self.apply_result_to_progress_and_persist();
+                self.current_state = GameState::Result;
+                self.setup_result_menu();
+                self.play_sound("assets/test_sound.wav");
+                return;
+            }
         }
 
         self.process_asset_server();
         self.build_renderables();
+        self.build_lights();
         self.push_hud_text();
     }
 
@@ -2153,9 +3484,25 @@ impl Game {
         self.setup_title_screen();
     }
 
+    /// Rebuilds `renderables` (and, from it, `draw_batches`/`batched_instances`) from scratch each
+    /// frame: cull to what the camera can actually see (`cull::cull_world`), assign atlas UVs
+    /// (`atlas::AtlasPacker`), then sort into instanced draw batches (`batch::batch_renderables`).
     pub fn build_renderables(&mut self) {
         self.renderables.clear();
-        for archetype in &self.world.archetypes {
+
+        let camera_bounds = cull::CameraBounds {
+            min: ffi::Vec2 { x: self.camera.x, y: self.camera.y },
+            max: ffi::Vec2 {
+                x: self.camera.x + SCREEN_WIDTH,
+                y: self.camera.y + SCREEN_HEIGHT,
+            },
+            margin: CULL_MARGIN,
+        };
+        let visible: HashSet<cull::EntityIndex> =
+            cull::cull_world(&self.world, &camera_bounds).into_iter().collect();
+        let mut atlas_packer = atlas::AtlasPacker::new();
+
+        for (archetype_idx, archetype) in self.world.archetypes.iter().enumerate() {
             let has_transform = archetype.types.contains(&ComponentType::Transform);
             let has_material = archetype.types.contains(&ComponentType::Material);
 
@@ -2173,38 +3520,160 @@ impl Game {
                     .downcast_ref::<Vec<Material>>()
                     .unwrap();
 
-                for (transform, material) in transforms.iter().zip(materials.iter()) {
+                for (row, (transform, material)) in
+                    transforms.iter().zip(materials.iter()).enumerate()
+                {
+                    if !visible.contains(&cull::EntityIndex { archetype: archetype_idx, row }) {
+                        continue;
+                    }
                     let texture_id = self
                         .texture_map
                         .get(&material.texture_handle)
                         .cloned()
                         .unwrap_or(0);
+                    let rect = atlas_packer.pack(
+                        texture_id,
+                        atlas::DEFAULT_SPRITE_TILE,
+                        atlas::DEFAULT_SPRITE_TILE,
+                    );
                     self.renderables.push(ffi::RenderableObject {
-                        transform: *transform,
+                        transform: self.camera.view(*transform),
                         mesh_id: 1,
                         material_id: 1,
                         texture_id,
+                        color: ffi::Vec4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 },
+                        atlas_layer: rect.layer,
+                        uv_min: ffi::Vec2 { x: rect.u0, y: rect.v0 },
+                        uv_max: ffi::Vec2 { x: rect.u1, y: rect.v1 },
+                    });
+                }
+            } else if has_transform && archetype.types.contains(&ComponentType::Particle) {
+                let transforms = archetype
+                    .storage
+                    .get(&ComponentType::Transform)
+                    .unwrap()
+                    .downcast_ref::<Vec<ffi::Transform>>()
+                    .unwrap();
+                let particles = archetype
+                    .storage
+                    .get(&ComponentType::Particle)
+                    .unwrap()
+                    .downcast_ref::<Vec<Particle>>()
+                    .unwrap();
+
+                for (row, (transform, particle)) in
+                    transforms.iter().zip(particles.iter()).enumerate()
+                {
+                    if !visible.contains(&cull::EntityIndex { archetype: archetype_idx, row }) {
+                        continue;
+                    }
+                    self.renderables.push(ffi::RenderableObject {
+                        transform: self.camera.view(*transform),
+                        mesh_id: PARTICLE_MESH_ID,
+                        material_id: PARTICLE_MATERIAL_ID,
+                        texture_id: 0,
+                        color: particle.color,
+                        atlas_layer: 0,
+                        uv_min: ffi::Vec2 { x: 0.0, y: 0.0 },
+                        uv_max: ffi::Vec2 { x: 1.0, y: 1.0 },
                     });
                 }
             }
         }
+
+        let (batches, instances) = batch::batch_renderables(&self.renderables);
+        self.draw_batches = batches;
+        self.batched_instances = instances;
+    }
+
+    /// Gathers every `Light` component in the world into a flat list for the host to upload to
+    /// the renderer, the same shape `build_renderables` already uses for `Material`+`Transform`.
+    pub fn build_lights(&mut self) {
+        self.lights.clear();
+        for archetype in &self.world.archetypes {
+            if archetype.types.contains(&ComponentType::Light) {
+                let lights = archetype
+                    .storage
+                    .get(&ComponentType::Light)
+                    .unwrap()
+                    .downcast_ref::<Vec<ffi::Light>>()
+                    .unwrap();
+                self.lights.extend(lights.iter().copied());
+            }
+        }
+    }
+
+    /// Plays `logical_path` through the FFI sound bridge, resolving it against `asset_server`'s
+    /// `roots` first so a mod-overridden sound effect is honored the same as an overridden
+    /// texture. Unlike `process_asset_server`'s texture/mesh loads, `ffi::play_sound` only takes a
+    /// file path (no bytes-delivery mode), so this can't honor a sound shipped solely inside a
+    /// mounted `.pkg` — only `roots` overrides are visible here until that FFI call grows a
+    /// bytes-based counterpart.
+    pub(crate) fn play_sound(&self, logical_path: &str) {
+        ffi::play_sound(&self.asset_server.resolve(logical_path));
     }
 
     pub fn process_asset_server(&mut self) {
         self.asset_commands.clear();
+        let mut to_dispatch = Vec::new();
         for (request_id, request) in self.asset_server.pending_requests.iter_mut() {
             if request.dispatched {
                 continue;
             }
+            to_dispatch.push((*request_id, request.command_type.clone(), request.path.clone()));
+            request.dispatched = true;
+        }
+
+        for (request_id, command_type, path) in to_dispatch {
+            if let Some(bytes) = self.asset_server.fetch_from_pack(&path) {
+                self.asset_command_bytes.insert(request_id, bytes);
+                self.asset_commands.push(ffi::AssetCommand {
+                    request_id,
+                    type_: command_type,
+                    path: String::new(),
+                    delivery: ffi::AssetDeliveryMode::Bytes,
+                });
+                continue;
+            }
 
             self.asset_commands.push(ffi::AssetCommand {
-                request_id: *request_id,
-                type_: request.command_type.clone(),
-                path: request.path.clone(),
+                request_id,
+                type_: command_type,
+                path: self.asset_server.resolve(&path),
+                delivery: ffi::AssetDeliveryMode::Path,
             });
-            request.dispatched = true;
         }
     }
+
+    /// Re-enqueues a load for `path`'s already-registered texture handle and immediately drops
+    /// the handle from `texture_map`, so `build_renderables` falls back to the untextured
+    /// placeholder (`texture_id: 0`) until `notify_asset_loaded` fires with the freshly reloaded
+    /// asset id. Returns `false` if `path` has no registered handle, or a reload for it is
+    /// already in flight.
+    pub fn reload_texture(&mut self, path: &str) -> bool {
+        let Some(&handle) = self.asset_server.texture_handle_map.get(path) else {
+            return false;
+        };
+        if !self.asset_server.reimport_texture(path) {
+            return false;
+        }
+        self.texture_map.remove(&handle);
+        true
+    }
+
+    /// Bulk counterpart to `reload_texture`: re-issues a load for every texture path
+    /// `AssetServer` is tracking, for a "reload everything dirty" content-iteration shortcut.
+    /// Returns the number of reloads actually queued.
+    pub fn reload_all_dirty_textures(&mut self) -> usize {
+        let paths: Vec<String> = self.asset_server.texture_handle_map.keys().cloned().collect();
+        let mut queued_count = 0usize;
+        for path in paths {
+            if self.reload_texture(&path) {
+                queued_count += 1;
+            }
+        }
+        queued_count
+    }
 }
 
 // --- VTable Functions ---
@@ -2242,17 +3711,256 @@ pub extern "C" fn deserialize_game(json: *const c_char) -> *mut Game {
     }
     let c_str = unsafe { CStr::from_ptr(json) };
     let r_str = c_str.to_str().unwrap();
-    let mut game: Game = serde_json::from_str(r_str).unwrap();
+    let game: Game = serde_json::from_str(r_str).unwrap();
+    Box::into_raw(Box::new(finish_deserialized_game(game)))
+}
+
+/// Current `format_version` written by `serialize_game_binary` and expected (at most) by
+/// `deserialize_game_checked`. Bumping this without adding an entry to `GAME_FORMAT_MIGRATIONS`
+/// means every existing snapshot below it fails to load instead of migrating.
+const GAME_FORMAT_VERSION: u32 = 1;
+/// Marks a buffer as `deserialize_game_checked`'s versioned JSON envelope: 4-byte magic, 4-byte
+/// little-endian `format_version`, then the JSON payload.
+const GAME_SNAPSHOT_MAGIC_JSON: [u8; 4] = *b"MYBJ";
+/// Same envelope shape as `GAME_SNAPSHOT_MAGIC_JSON`, but the payload is `bincode`-encoded instead
+/// of JSON. This is the format `serialize_game_binary` emits.
+const GAME_SNAPSHOT_MAGIC_BIN: [u8; 4] = *b"MYBB";
+
+/// One upgrade step per historical `format_version`, applied in order starting from whatever
+/// version a loaded snapshot reports, entry `i` upgrading version `i` to version `i + 1`. Empty
+/// today because `format_version` 1 is the first version this envelope ever shipped with — it
+/// versions the wire format itself, not a schema change — so a legacy unversioned JSON save (read
+/// as version 0) and a version-1 snapshot parse identically. Add an entry here, and bump
+/// `GAME_FORMAT_VERSION`, the next time `Game`'s shape changes in a way older snapshots need
+/// translated.
+const GAME_FORMAT_MIGRATIONS: &[fn(serde_json::Value) -> serde_json::Value] = &[];
+
+pub const DESERIALIZE_OK: i32 = 0;
+pub const DESERIALIZE_NULL_INPUT: i32 = -1;
+pub const DESERIALIZE_INVALID_ENCODING: i32 = -2;
+pub const DESERIALIZE_UNKNOWN_VERSION: i32 = -3;
+pub const DESERIALIZE_SCHEMA_MISMATCH: i32 = -4;
+
+/// A heap buffer handed across the FFI boundary; paired with `free_byte_buffer`. Mirrors the
+/// `*Slice` structs above, except the bytes are owned (not a view into a `Game` field) so they
+/// must be freed independently of the `Game` that produced them.
+#[repr(C)]
+pub struct ByteBuffer {
+    ptr: *mut u8,
+    len: usize,
+}
+
+impl ByteBuffer {
+    fn from_vec(bytes: Vec<u8>) -> Self {
+        // `into_boxed_slice` drops any spare capacity, so `len` below is also the allocation's
+        // exact capacity — required for `free_byte_buffer` to reconstruct the same layout.
+        let mut boxed = bytes.into_boxed_slice();
+        let buffer = ByteBuffer {
+            ptr: boxed.as_mut_ptr(),
+            len: boxed.len(),
+        };
+        std::mem::forget(boxed);
+        buffer
+    }
+
+    fn empty() -> Self {
+        ByteBuffer {
+            ptr: ptr::null_mut(),
+            len: 0,
+        }
+    }
+}
+
+/// Shared post-processing for every path that turns parsed save bytes into a live `Game`:
+/// clamps settings, re-derives the fields that track `save_data` rather than own it, re-applies
+/// runtime audio/fullscreen/BGM state, and re-initializes fields `#[serde(skip)]` left default.
+fn finish_deserialized_game(mut game: Game) -> Game {
     game.save_data = game.save_data.sanitized();
     game.total_play_count = game.save_data.progress.total_play_count;
-    game.save_file_path = PathBuf::from(SAVE_FILE_REL_PATH);
+    game.save_file_path = profile_path(game.active_slot);
     game.apply_runtime_audio_settings();
     game.apply_runtime_fullscreen_setting();
     game.apply_runtime_bgm_for_state();
     // Re-initialize non-serializable fields
     game.asset_server = AssetServer::new();
     // ... etc. for other non-serde fields
-    Box::into_raw(Box::new(game))
+    game
+}
+
+/// Runs `found_version`'s upgrade steps (and everything after it) over a JSON payload so it
+/// matches the shape `Game`'s `Deserialize` impl expects at `GAME_FORMAT_VERSION`.
+fn migrate_json_payload(payload: serde_json::Value, found_version: u32) -> serde_json::Value {
+    GAME_FORMAT_MIGRATIONS
+        .iter()
+        .skip(found_version as usize)
+        .fold(payload, |value, migration| migration(value))
+}
+
+/// Parses a `deserialize_game_checked` buffer, handling the versioned JSON/binary envelopes and
+/// falling back to treating an unrecognized (non-magic-prefixed) buffer as a legacy unversioned
+/// JSON save (`format_version` 0).
+fn parse_checked_game(bytes: &[u8]) -> Result<Game, i32> {
+    if bytes.len() >= 8 && bytes.starts_with(&GAME_SNAPSHOT_MAGIC_JSON) {
+        let format_version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if format_version > GAME_FORMAT_VERSION {
+            return Err(DESERIALIZE_UNKNOWN_VERSION);
+        }
+        let text = std::str::from_utf8(&bytes[8..]).map_err(|_| DESERIALIZE_INVALID_ENCODING)?;
+        let value: serde_json::Value =
+            serde_json::from_str(text).map_err(|_| DESERIALIZE_SCHEMA_MISMATCH)?;
+        let value = migrate_json_payload(value, format_version);
+        return serde_json::from_value(value).map_err(|_| DESERIALIZE_SCHEMA_MISMATCH);
+    }
+
+    if bytes.len() >= 8 && bytes.starts_with(&GAME_SNAPSHOT_MAGIC_BIN) {
+        let format_version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if format_version > GAME_FORMAT_VERSION {
+            return Err(DESERIALIZE_UNKNOWN_VERSION);
+        }
+        return bincode::deserialize(&bytes[8..]).map_err(|_| DESERIALIZE_SCHEMA_MISMATCH);
+    }
+
+    // No recognized magic: a save written before this envelope existed, i.e. format_version 0.
+    let text = std::str::from_utf8(bytes).map_err(|_| DESERIALIZE_INVALID_ENCODING)?;
+    let value: serde_json::Value =
+        serde_json::from_str(text).map_err(|_| DESERIALIZE_SCHEMA_MISMATCH)?;
+    let value = migrate_json_payload(value, 0);
+    serde_json::from_value(value).map_err(|_| DESERIALIZE_SCHEMA_MISMATCH)
+}
+
+/// Fallible, versioned alternative to `deserialize_game`: never unwraps/panics on truncated or
+/// corrupt input, writes the parsed `Game` through `out_game` only on success, and returns
+/// `DESERIALIZE_OK` (`0`) or one of the negative `DESERIALIZE_*` error codes.
+#[no_mangle]
+pub extern "C" fn deserialize_game_checked(
+    bytes: *const u8,
+    len: usize,
+    out_game: *mut *mut Game,
+) -> i32 {
+    if bytes.is_null() || out_game.is_null() {
+        return DESERIALIZE_NULL_INPUT;
+    }
+    let slice = unsafe { std::slice::from_raw_parts(bytes, len) };
+    match parse_checked_game(slice) {
+        Ok(game) => {
+            let game = finish_deserialized_game(game);
+            unsafe {
+                *out_game = Box::into_raw(Box::new(game));
+            }
+            DESERIALIZE_OK
+        }
+        Err(code) => code,
+    }
+}
+
+/// Encodes `game` as the versioned `bincode` envelope `deserialize_game_checked` understands
+/// (`GAME_SNAPSHOT_MAGIC_BIN` + `GAME_FORMAT_VERSION` + payload), for callers that want a compact
+/// binary save instead of `serialize_game`'s JSON text.
+#[no_mangle]
+pub extern "C" fn serialize_game_binary(game: *const Game) -> ByteBuffer {
+    if game.is_null() {
+        return ByteBuffer::empty();
+    }
+    let game = unsafe { &*game };
+    let Ok(payload) = bincode::serialize(game) else {
+        return ByteBuffer::empty();
+    };
+
+    let mut buffer = Vec::with_capacity(8 + payload.len());
+    buffer.extend_from_slice(&GAME_SNAPSHOT_MAGIC_BIN);
+    buffer.extend_from_slice(&GAME_FORMAT_VERSION.to_le_bytes());
+    buffer.extend_from_slice(&payload);
+    ByteBuffer::from_vec(buffer)
+}
+
+/// Frees a `ByteBuffer` returned by `serialize_game_binary`.
+#[no_mangle]
+pub extern "C" fn free_byte_buffer(buffer: ByteBuffer) {
+    if buffer.ptr.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Vec::from_raw_parts(buffer.ptr, buffer.len, buffer.len));
+    }
+}
+
+pub const NETPLAY_OK: i32 = 0;
+pub const NETPLAY_NULL_INPUT: i32 = -1;
+pub const NETPLAY_DECODE_ERROR: i32 = -2;
+pub const NETPLAY_ENCODE_ERROR: i32 = -3;
+
+/// Leaks `bytes` as an exactly-sized allocation and returns its raw parts, for FFI functions that
+/// hand ownership back through `*mut *mut u8`/`*mut usize` out-parameters instead of `ByteBuffer`.
+fn leak_bytes(bytes: Vec<u8>) -> (*mut u8, usize) {
+    let mut boxed = bytes.into_boxed_slice();
+    let ptr = boxed.as_mut_ptr();
+    let len = boxed.len();
+    std::mem::forget(boxed);
+    (ptr, len)
+}
+
+/// Diffs `game`'s current `netplay::GameSnapshot` against `base_snapshot` (a CBOR-encoded
+/// snapshot the receiver already has) and writes the CBOR-encoded `netplay::GameSnapshotDelta`
+/// through `out_ptr`/`out_len`. The returned buffer must be freed with `free_game_delta_buffer`.
+/// Returns `NETPLAY_OK` (`0`) or one of the negative `NETPLAY_*` error codes.
+#[no_mangle]
+pub extern "C" fn serialize_game_delta(
+    game: *const Game,
+    base_snapshot: *const u8,
+    base_len: usize,
+    out_ptr: *mut *mut u8,
+    out_len: *mut usize,
+) -> i32 {
+    if game.is_null() || base_snapshot.is_null() || out_ptr.is_null() || out_len.is_null() {
+        return NETPLAY_NULL_INPUT;
+    }
+    let game = unsafe { &*game };
+    let base_bytes = unsafe { std::slice::from_raw_parts(base_snapshot, base_len) };
+    let Ok(base) = serde_cbor::from_slice::<netplay::GameSnapshot>(base_bytes) else {
+        return NETPLAY_DECODE_ERROR;
+    };
+
+    let delta = netplay::GameSnapshot::capture(game).diff(&base);
+    let Ok(encoded) = serde_cbor::to_vec(&delta) else {
+        return NETPLAY_ENCODE_ERROR;
+    };
+
+    let (ptr, len) = leak_bytes(encoded);
+    unsafe {
+        *out_ptr = ptr;
+        *out_len = len;
+    }
+    NETPLAY_OK
+}
+
+/// Frees a buffer returned by `serialize_game_delta` through its `out_ptr`/`out_len`.
+#[no_mangle]
+pub extern "C" fn free_game_delta_buffer(ptr: *mut u8, len: usize) {
+    if ptr.is_null() {
+        return;
+    }
+    unsafe {
+        drop(Vec::from_raw_parts(ptr, len, len));
+    }
+}
+
+/// Decodes a CBOR-encoded `netplay::GameSnapshotDelta` and applies it onto `game`'s live
+/// deterministic-simulation fields, the receiving side of `serialize_game_delta`. Returns
+/// `NETPLAY_OK` (`0`) or one of the negative `NETPLAY_*` error codes.
+#[no_mangle]
+pub extern "C" fn apply_game_delta(game: *mut Game, bytes: *const u8, len: usize) -> i32 {
+    if game.is_null() || bytes.is_null() {
+        return NETPLAY_NULL_INPUT;
+    }
+    let game = unsafe { &mut *game };
+    let slice = unsafe { std::slice::from_raw_parts(bytes, len) };
+    let Ok(delta) = serde_cbor::from_slice::<netplay::GameSnapshotDelta>(slice) else {
+        return NETPLAY_DECODE_ERROR;
+    };
+
+    let applied = netplay::GameSnapshot::capture(game).apply(&delta);
+    applied.apply_to(game);
+    NETPLAY_OK
 }
 
 #[no_mangle]
@@ -2289,6 +3997,21 @@ pub extern "C" fn get_renderables(game: *mut Game) -> RenderableObjectSlice {
     }
 }
 
+#[no_mangle]
+pub extern "C" fn get_lights(game: *mut Game) -> LightSlice {
+    if game.is_null() {
+        return LightSlice {
+            ptr: ptr::null(),
+            len: 0,
+        };
+    }
+    let game = unsafe { &*game };
+    LightSlice {
+        ptr: game.lights.as_ptr(),
+        len: game.lights.len(),
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn get_asset_commands(game: *mut Game) -> AssetCommandSlice {
     if game.is_null() {
@@ -2313,12 +4036,64 @@ pub extern "C" fn clear_asset_commands(game: *mut Game) {
     game.asset_commands.clear();
 }
 
+/// Pushes `root` as the next-lowest-priority asset root (see `AssetServer::push_root`). Callers
+/// mounting a mod or DLC pack should push their override directory before the base install's
+/// root, so it's tried first when `resolve` walks the list.
+#[no_mangle]
+pub extern "C" fn asset_server_push_root(game: *mut Game, root: *const c_char) {
+    if game.is_null() || root.is_null() {
+        return;
+    }
+    let game = unsafe { &mut *game };
+    let Ok(root) = unsafe { CStr::from_ptr(root) }.to_str() else {
+        return;
+    };
+    game.asset_server.push_root(root);
+}
+
+/// Clears every registered asset root, reverting to resolving logical paths unchanged.
+#[no_mangle]
+pub extern "C" fn asset_server_clear_roots(game: *mut Game) {
+    if game.is_null() {
+        return;
+    }
+    let game = unsafe { &mut *game };
+    game.asset_server.clear_roots();
+}
+
+/// Re-enqueues a load for an already-registered texture path and marks it stale (see
+/// `Game::reload_texture`), for "tweak the texture, see it live" content iteration.
+/// Returns `true` if a reload was actually queued.
+#[no_mangle]
+pub extern "C" fn request_asset_reload(game: *mut Game, path: *const c_char) -> bool {
+    if game.is_null() || path.is_null() {
+        return false;
+    }
+    let game = unsafe { &mut *game };
+    let Ok(path) = unsafe { CStr::from_ptr(path) }.to_str() else {
+        return false;
+    };
+    game.reload_texture(path)
+}
+
+/// Bulk counterpart to `request_asset_reload`: re-issues a load for every tracked texture path.
+/// Returns the number of reloads queued.
+#[no_mangle]
+pub extern "C" fn request_reload_all_dirty(game: *mut Game) -> usize {
+    if game.is_null() {
+        return 0;
+    }
+    let game = unsafe { &mut *game };
+    game.reload_all_dirty_textures()
+}
+
 #[no_mangle]
 pub extern "C" fn notify_asset_loaded(game: *mut Game, request_id: u32, asset_id: u32) {
     if game.is_null() {
         return;
     }
     let game = unsafe { &mut *game };
+    game.asset_command_bytes.remove(&request_id);
     if let Some(request) = game.asset_server.pending_requests.remove(&request_id) {
         if let Some(handle) = game.asset_server.texture_handle_map.get_mut(&request.path) {
             game.texture_map.insert(*handle, asset_id);
@@ -2326,6 +4101,39 @@ pub extern "C" fn notify_asset_loaded(game: *mut Game, request_id: u32, asset_id
     }
 }
 
+#[no_mangle]
+pub extern "C" fn notify_model_loaded(game: *mut Game, request_id: u32, asset_id: u32) {
+    if game.is_null() {
+        return;
+    }
+    let game = unsafe { &mut *game };
+    game.asset_command_bytes.remove(&request_id);
+    if let Some(request) = game.asset_server.pending_requests.remove(&request_id) {
+        if let Some(handle) = game.asset_server.mesh_handle_map.get_mut(&request.path) {
+            game.mesh_map.insert(*handle, asset_id);
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn notify_model_nodes_loaded(
+    game: *mut Game,
+    model_handle: u32,
+    nodes: *const ffi::ModelNode,
+    nodes_len: usize,
+) {
+    if game.is_null() || (nodes.is_null() && nodes_len > 0) {
+        return;
+    }
+    let game = unsafe { &mut *game };
+    let nodes = if nodes_len == 0 {
+        Vec::new()
+    } else {
+        unsafe { std::slice::from_raw_parts(nodes, nodes_len) }.to_vec()
+    };
+    game.asset_server.resolve_model_nodes(model_handle, nodes);
+}
+
 #[no_mangle]
 pub extern "C" fn update_input_state(game: *mut Game, input: *const ffi::InputState) {
     if game.is_null() || input.is_null() {
@@ -2345,6 +4153,53 @@ pub extern "C" fn get_asset_command_path_cstring(command: *const ffi::AssetComma
     CString::new(command.path.as_str()).unwrap().into_raw()
 }
 
+/// Returns the decompressed bytes for a bytes-backed `AssetCommand` (`delivery ==
+/// AssetDeliveryMode::Bytes`). The returned slice stays valid until the host calls
+/// `notify_asset_loaded`/`notify_model_loaded` for the command's `request_id`, mirroring how
+/// `asset_commands` itself stays valid until `clear_asset_commands`.
+#[no_mangle]
+pub extern "C" fn get_asset_command_bytes(
+    game: *mut Game,
+    command: *const ffi::AssetCommand,
+) -> AssetBytesSlice {
+    if game.is_null() || command.is_null() {
+        return AssetBytesSlice {
+            ptr: ptr::null(),
+            len: 0,
+        };
+    }
+    let game = unsafe { &*game };
+    let command = unsafe { &*command };
+    match game.asset_command_bytes.get(&command.request_id) {
+        Some(bytes) => AssetBytesSlice {
+            ptr: bytes.as_ptr(),
+            len: bytes.len(),
+        },
+        None => AssetBytesSlice {
+            ptr: ptr::null(),
+            len: 0,
+        },
+    }
+}
+
+/// Mounts `path` as an `AssetPack`, next-lowest-priority after every pack mounted so far (see
+/// `AssetServer::mount_pack`). Returns `0` on success, `1` if `game`/`path` is null or not valid
+/// UTF-8, `2` if the pack couldn't be opened or parsed.
+#[no_mangle]
+pub extern "C" fn mount_asset_pack(game: *mut Game, path: *const c_char) -> u32 {
+    if game.is_null() || path.is_null() {
+        return 1;
+    }
+    let game = unsafe { &mut *game };
+    let Ok(path) = unsafe { CStr::from_ptr(path) }.to_str() else {
+        return 1;
+    };
+    match game.asset_server.mount_pack(path) {
+        Ok(()) => 0,
+        Err(_) => 2,
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn get_text_commands(game: *mut Game) -> TextCommandSlice {
     if game.is_null() {
@@ -2369,6 +4224,51 @@ pub extern "C" fn get_text_command_text_cstring(command: *const ffi::TextCommand
     CString::new(command.text.as_str()).unwrap().into_raw()
 }
 
+#[no_mangle]
+pub extern "C" fn get_rect_commands(game: *mut Game) -> RectCommandSlice {
+    if game.is_null() {
+        return RectCommandSlice {
+            ptr: ptr::null(),
+            len: 0,
+        };
+    }
+    let game = unsafe { &*game };
+    RectCommandSlice {
+        ptr: game.rect_commands.as_ptr(),
+        len: game.rect_commands.len(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn get_draw_batches(game: *mut Game) -> DrawBatchSlice {
+    if game.is_null() {
+        return DrawBatchSlice {
+            ptr: ptr::null(),
+            len: 0,
+        };
+    }
+    let game = unsafe { &*game };
+    DrawBatchSlice {
+        ptr: game.draw_batches.as_ptr(),
+        len: game.draw_batches.len(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn get_batched_instances(game: *mut Game) -> TransformSlice {
+    if game.is_null() {
+        return TransformSlice {
+            ptr: ptr::null(),
+            len: 0,
+        };
+    }
+    let game = unsafe { &*game };
+    TransformSlice {
+        ptr: game.batched_instances.as_ptr(),
+        len: game.batched_instances.len(),
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn free_cstring(s: *mut c_char) {
     if !s.is_null() {
@@ -0,0 +1,219 @@
+//! Locale string tables for menu/HUD text.
+//!
+//! A `Locale` is a `language -> key -> translated string` lookup, loaded from a JSON/RON table the
+//! same way `scene::SceneData::load` dispatches on file extension. Missing keys (and missing or
+//! unparsable locale files) fall back gracefully rather than panicking, so partial translations or
+//! a missing `assets/locale/*.json` never take the menus down.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+/// A language `Locale` can be loaded for. Cycled by the title/pause "Language" button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Language {
+    En,
+    Ja,
+}
+
+impl Language {
+    /// The next language in the cycle, wrapping back to the first.
+    pub fn next(self) -> Self {
+        match self {
+            Language::En => Language::Ja,
+            Language::Ja => Language::En,
+        }
+    }
+
+    /// Short code shown on the language button itself (not looked up, since it names the
+    /// language rather than describing it in it).
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "EN",
+            Language::Ja => "JA",
+        }
+    }
+
+    fn asset_path(self) -> &'static str {
+        match self {
+            Language::En => "assets/locale/en.json",
+            Language::Ja => "assets/locale/ja.json",
+        }
+    }
+}
+
+impl Default for Language {
+    fn default() -> Self {
+        Language::En
+    }
+}
+
+#[derive(Debug)]
+pub enum LocaleError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    RonSpanned(ron::de::SpannedError),
+}
+
+impl Display for LocaleError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LocaleError::Io(e) => write!(f, "I/O error: {e}"),
+            LocaleError::Json(e) => write!(f, "JSON error: {e}"),
+            LocaleError::RonSpanned(e) => write!(f, "RON error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for LocaleError {}
+
+impl From<std::io::Error> for LocaleError {
+    fn from(value: std::io::Error) -> Self {
+        LocaleError::Io(value)
+    }
+}
+
+impl From<serde_json::Error> for LocaleError {
+    fn from(value: serde_json::Error) -> Self {
+        LocaleError::Json(value)
+    }
+}
+
+impl From<ron::de::SpannedError> for LocaleError {
+    fn from(value: ron::de::SpannedError) -> Self {
+        LocaleError::RonSpanned(value)
+    }
+}
+
+/// A loaded string table for one `Language`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Locale {
+    pub language: Language,
+    strings: HashMap<String, String>,
+}
+
+impl Locale {
+    /// Loads the table for `language` from `assets/locale/{en,ja}.json`, dispatching on extension
+    /// the same way `SceneData::load` does (`.ron` parses as RON, anything else as JSON). Falls
+    /// back to `Self::builtin` when the file is missing or fails to parse, so the game stays fully
+    /// playable without shipping locale assets.
+    pub fn load(language: Language) -> Self {
+        match Self::load_from(Path::new(language.asset_path())) {
+            Ok(strings) => Locale { language, strings },
+            Err(_) => Self::builtin(language),
+        }
+    }
+
+    fn load_from(path: &Path) -> Result<HashMap<String, String>, LocaleError> {
+        let text = fs::read_to_string(path)?;
+        let is_ron = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.eq_ignore_ascii_case("ron"))
+            .unwrap_or(false);
+
+        if is_ron {
+            Ok(ron::de::from_str(&text)?)
+        } else {
+            Ok(serde_json::from_str(&text)?)
+        }
+    }
+
+    /// Hardcoded fallback table, used when no locale asset is present on disk.
+    fn builtin(language: Language) -> Self {
+        let strings = match language {
+            Language::En => builtin_en(),
+            Language::Ja => builtin_ja(),
+        };
+        Locale { language, strings }
+    }
+
+    /// Looks up `key`; a missing key falls back to the key itself, so a partially translated
+    /// table degrades to readable (if untranslated) English key names instead of blank text.
+    pub fn get(&self, key: &str) -> String {
+        self.strings
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| key.to_string())
+    }
+}
+
+fn builtin_en() -> HashMap<String, String> {
+    [
+        ("profile.title", "Select Profile"),
+        ("profile.select", "Slot"),
+        ("profile.delete", "Delete"),
+        ("profile.summary", "Best:{score}  Play:{play}  Clear:{clear}"),
+        ("menu.title", "MIYABI Box Survival"),
+        ("menu.controls", "Arrow Keys: Move / ESC: Pause"),
+        ("menu.reimport", "U: Reimport Textures"),
+        ("menu.settings_header", "Settings (auto-saved)"),
+        ("menu.start", "Start Game"),
+        ("menu.resume", "Resume"),
+        ("menu.back_to_title", "Back To Title"),
+        ("menu.retry", "Retry"),
+        ("pause.title", "PAUSED"),
+        ("result.clear", "CLEAR"),
+        ("result.game_over", "GAME OVER"),
+        ("result.score", "Score: {score}"),
+        ("result.survival", "Survival: {sec} sec"),
+        ("result.high_score", "High Score: {score}"),
+        ("result.best_survival", "Best Survival: {sec} sec"),
+        ("result.play_clear", "Play:{play}  Clear:{clear}"),
+        ("result.save_replay", "Save Replay"),
+        ("result.seed", "Seed: {seed}"),
+        ("settings.master_volume", "Master Volume: {pct}%"),
+        ("settings.bgm_volume", "BGM Volume: {pct}%"),
+        ("settings.se_volume", "SE Volume: {pct}%"),
+        ("settings.fullscreen", "Fullscreen: {state}"),
+        ("settings.fullscreen_on", "ON"),
+        ("settings.fullscreen_off", "OFF"),
+        ("settings.toggle_fullscreen", "Toggle Fullscreen"),
+        ("settings.language", "Language"),
+        ("hud.status", "HP:{hp}  Time:{time}s  Score:{score}  Lv:{lv}"),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect()
+}
+
+fn builtin_ja() -> HashMap<String, String> {
+    [
+        ("profile.title", "プロフィール選択"),
+        ("profile.select", "スロット"),
+        ("profile.delete", "削除"),
+        ("profile.summary", "ベスト:{score}  プレイ:{play}  クリア:{clear}"),
+        ("menu.title", "MIYABI ボックスサバイバル"),
+        ("menu.controls", "矢印キー: 移動 / ESC: ポーズ"),
+        ("menu.reimport", "U: テクスチャ再読み込み"),
+        ("menu.settings_header", "設定 (自動保存)"),
+        ("menu.start", "ゲーム開始"),
+        ("menu.resume", "再開"),
+        ("menu.back_to_title", "タイトルへ戻る"),
+        ("menu.retry", "リトライ"),
+        ("pause.title", "一時停止"),
+        ("result.clear", "クリア"),
+        ("result.game_over", "ゲームオーバー"),
+        ("result.score", "スコア: {score}"),
+        ("result.survival", "生存時間: {sec} 秒"),
+        ("result.high_score", "ハイスコア: {score}"),
+        ("result.best_survival", "最長生存: {sec} 秒"),
+        ("result.play_clear", "プレイ:{play}  クリア:{clear}"),
+        ("result.save_replay", "リプレイを保存"),
+        ("result.seed", "シード: {seed}"),
+        ("settings.master_volume", "マスター音量: {pct}%"),
+        ("settings.bgm_volume", "BGM音量: {pct}%"),
+        ("settings.se_volume", "SE音量: {pct}%"),
+        ("settings.fullscreen", "フルスクリーン: {state}"),
+        ("settings.fullscreen_on", "オン"),
+        ("settings.fullscreen_off", "オフ"),
+        ("settings.toggle_fullscreen", "フルスクリーン切替"),
+        ("settings.language", "言語"),
+        ("hud.status", "HP:{hp}  時間:{time}秒  スコア:{score}  Lv:{lv}"),
+    ]
+    .into_iter()
+    .map(|(k, v)| (k.to_string(), v.to_string()))
+    .collect()
+}
@@ -0,0 +1,176 @@
+//! Compact per-tick state for networked play: a `GameSnapshot` captures only the fields a
+//! deterministic simulation actually needs to resync (not the whole `Game`, which also carries
+//! render/asset/UI bookkeeping no remote peer cares about), and `GameSnapshotDelta` ships just
+//! what changed since a previously-acknowledged snapshot. `ClientMessage`/`ServerMessage` mirror
+//! the asymmetry of the wire protocol itself: the client only ever sends its own input, the
+//! server is the only side allowed to assert authoritative state.
+
+use crate::{ffi, Game, GameState, SaveProgress};
+use serde::{Deserialize, Serialize};
+
+/// The deterministic-simulation subset of `Game`: enough to resume the simulation on a remote
+/// peer, deliberately excluding everything render/asset-server/UI related that either doesn't
+/// affect the simulation or is rebuilt locally every frame anyway.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    pub current_state: GameState,
+    pub input_state: ffi::InputState,
+    /// `rng::XorShift::state()` — the entire generator, since it's a single `u32`.
+    pub rng_state: u32,
+    pub progress: SaveProgress,
+}
+
+impl GameSnapshot {
+    /// Pulls the deterministic-simulation fields out of a live `Game`.
+    pub fn capture(game: &Game) -> GameSnapshot {
+        GameSnapshot {
+            current_state: game.current_state,
+            input_state: game.input_state,
+            rng_state: game.rng.state(),
+            progress: game.save_data.progress.clone(),
+        }
+    }
+
+    /// Writes this snapshot's fields back onto a live `Game`, the inverse of `capture`.
+    pub fn apply_to(&self, game: &mut Game) {
+        game.current_state = self.current_state;
+        game.input_state = self.input_state;
+        game.rng = crate::rng::XorShift::from_state(self.rng_state);
+        game.save_data.progress = self.progress.clone();
+    }
+
+    /// Diffs `self` against `base`, a previously-captured snapshot the receiver is assumed to
+    /// already have. Only fields that actually changed are `Some` in the result.
+    pub fn diff(&self, base: &GameSnapshot) -> GameSnapshotDelta {
+        GameSnapshotDelta {
+            current_state: (self.current_state != base.current_state).then_some(self.current_state),
+            input_state: (self.input_state != base.input_state).then_some(self.input_state),
+            rng_state: (self.rng_state != base.rng_state).then_some(self.rng_state),
+            progress: (self.progress != base.progress).then(|| self.progress.clone()),
+        }
+    }
+
+    /// Reconstructs the snapshot `delta` was diffed from, taking `self` as the fields `delta`
+    /// left unset. `self` is usually the last snapshot this side successfully applied.
+    pub fn apply(&self, delta: &GameSnapshotDelta) -> GameSnapshot {
+        GameSnapshot {
+            current_state: delta.current_state.unwrap_or(self.current_state),
+            input_state: delta.input_state.unwrap_or(self.input_state),
+            rng_state: delta.rng_state.unwrap_or(self.rng_state),
+            progress: delta.progress.clone().unwrap_or_else(|| self.progress.clone()),
+        }
+    }
+}
+
+/// A `GameSnapshot` diffed against a previously-captured one: `None` means "unchanged since the
+/// base snapshot", so only the fields that actually moved this tick cross the wire.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameSnapshotDelta {
+    pub current_state: Option<GameState>,
+    pub input_state: Option<ffi::InputState>,
+    pub rng_state: Option<u32>,
+    pub progress: Option<SaveProgress>,
+}
+
+/// Messages the client sends the server. Deliberately thin: the client only ever reports its own
+/// input, never asserts simulation state (the server is authoritative).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ClientMessage {
+    /// Sent every tick: the local player's current input, the high-frequency half of netplay
+    /// traffic.
+    InputState(ffi::InputState),
+}
+
+/// Messages the server sends each client/spectator. The authoritative half of the protocol: a
+/// `StateDelta` each tick once a peer has a base snapshot to diff against, or a `FullSnapshot`
+/// when it doesn't (first connect, or recovering from a delta that failed to apply).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ServerMessage {
+    StateDelta(GameSnapshotDelta),
+    FullSnapshot(GameSnapshot),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::XorShift;
+    use std::collections::HashSet;
+
+    fn snapshot(seed: u32, score: u32, esc_key: bool) -> GameSnapshot {
+        GameSnapshot {
+            current_state: GameState::InGame,
+            input_state: ffi::InputState {
+                up: seed % 2 == 0,
+                down: false,
+                left: false,
+                right: false,
+                esc_key,
+                s_key: false,
+                p_key: false,
+                u_key: false,
+                mouse_pos: ffi::Vec2 { x: seed as f32, y: 0.0 },
+                mouse_clicked: false,
+            },
+            rng_state: XorShift::new(seed).state(),
+            progress: SaveProgress {
+                best_score: score,
+                best_survival_sec: 0,
+                total_play_count: 1,
+                total_clear_count: 0,
+                last_seed: seed,
+                script_flags: HashSet::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn diff_then_apply_round_trips_for_a_single_changed_field() {
+        let base = snapshot(1, 10, false);
+        let mut updated = base.clone();
+        updated.progress.best_score = 99;
+
+        let delta = updated.diff(&base);
+        assert_eq!(delta.progress, Some(updated.progress.clone()));
+        assert_eq!(delta.current_state, None);
+        assert_eq!(delta.input_state, None);
+        assert_eq!(delta.rng_state, None);
+
+        assert_eq!(base.apply(&delta), updated);
+    }
+
+    #[test]
+    fn identical_snapshots_diff_to_an_empty_delta() {
+        let snap = snapshot(42, 5, true);
+        let delta = snap.diff(&snap);
+        assert_eq!(delta, GameSnapshotDelta::default());
+        assert_eq!(snap.apply(&delta), snap);
+    }
+
+    #[test]
+    fn apply_serialize_round_trips_for_randomized_snapshots() {
+        for seed in [0u32, 1, 7, 1234, u32::MAX, 0xDEAD_BEEF] {
+            let base = snapshot(seed, seed % 100, seed % 3 == 0);
+            let mut target = snapshot(seed.wrapping_add(1), (seed % 100) + 1, seed % 2 == 0);
+            target.current_state = if seed % 2 == 0 {
+                GameState::Pause
+            } else {
+                GameState::InGame
+            };
+
+            let delta = target.diff(&base);
+            let encoded = serde_cbor::to_vec(&delta).unwrap();
+            let decoded: GameSnapshotDelta = serde_cbor::from_slice(&encoded).unwrap();
+
+            assert_eq!(base.apply(&decoded), target, "seed={seed}");
+        }
+    }
+
+    #[test]
+    fn full_snapshot_message_round_trips_through_cbor() {
+        let snap = snapshot(9, 3, false);
+        let message = ServerMessage::FullSnapshot(snap.clone());
+        let encoded = serde_cbor::to_vec(&message).unwrap();
+        let decoded: ServerMessage = serde_cbor::from_slice(&encoded).unwrap();
+        assert_eq!(decoded, ServerMessage::FullSnapshot(snap));
+    }
+}
@@ -1,8 +1,64 @@
-use crate::{ffi, ComponentType, InternalWorld, Material, Sprite};
+use crate::{atlas, batch, cull, ffi, gpu, ComponentType, InternalWorld, Material, Sprite};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
+/// Minimum wall-clock gap between two progress callbacks for the same scenario, so reporting a
+/// few times a second doesn't dominate the measured iterations themselves.
+const PROGRESS_REPORT_INTERVAL_MS: f64 = 200.0;
+
+/// Monotonic millisecond clock behind every timing measurement in this module: `Instant` panics
+/// on first use on `wasm32-unknown-unknown`, so the browser build reads `performance.now()`
+/// instead, the same clock the JS event loop uses. Only differences between two calls are
+/// meaningful — the zero point isn't the Unix epoch on either target.
+#[cfg(not(target_arch = "wasm32"))]
+fn now_ms() -> f64 {
+    use std::sync::OnceLock;
+    static START: OnceLock<Instant> = OnceLock::new();
+    START.get_or_init(Instant::now).elapsed().as_secs_f64() * 1000.0
+}
+
+#[cfg(target_arch = "wasm32")]
+fn now_ms() -> f64 {
+    web_sys::window()
+        .and_then(|window| window.performance())
+        .map(|performance| performance.now())
+        .unwrap_or(0.0)
+}
+
+/// Wall-clock time the report was generated, in seconds since the Unix epoch. `SystemTime::now()`
+/// panics on `wasm32-unknown-unknown`, so the browser build reads `Date.now()` instead, which
+/// answers the same question in milliseconds.
+#[cfg(not(target_arch = "wasm32"))]
+fn generated_unix_epoch_sec() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn generated_unix_epoch_sec() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchmarkPhase {
+    Warmup,
+    Measure,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ProgressEvent<'a> {
+    pub scenario: &'a str,
+    pub phase: BenchmarkPhase,
+    pub current: u32,
+    pub total: u32,
+    pub estimated_remaining: Duration,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerfConfig {
     pub warmup_iterations: u32,
@@ -11,6 +67,21 @@ pub struct PerfConfig {
     pub ui_items_per_row: usize,
     pub ui_items_per_col: usize,
     pub scene_entity_count: usize,
+    /// Width/height of the bit-packed board used by the `game_of_life_step` scenario.
+    pub life_grid_width: usize,
+    pub life_grid_height: usize,
+    /// Number of Game of Life generations advanced per measured iteration.
+    pub life_generations: u32,
+    /// Fixed seed for the board's initial state, so the scenario is comparable across machines.
+    pub life_seed: u64,
+    /// When true, skip Tukey-fence outlier rejection and summarize the raw sample set.
+    pub keep_outliers: bool,
+    /// Opt-in: also run the `gpu_draw_call` scenario (requires the `gpu_bench` feature).
+    pub gpu_benchmark: bool,
+    /// When true, each `PerfScenarioResult` retains its raw per-iteration `samples` so
+    /// `compare_reports` can run a Mann–Whitney U test against another report; off by default to
+    /// keep serialized reports small.
+    pub retain_samples: bool,
 }
 
 impl Default for PerfConfig {
@@ -22,6 +93,13 @@ impl Default for PerfConfig {
             ui_items_per_row: 30,
             ui_items_per_col: 40,
             scene_entity_count: 5_000,
+            life_grid_width: 256,
+            life_grid_height: 256,
+            life_generations: 10,
+            life_seed: 0x2545_F491,
+            keep_outliers: false,
+            gpu_benchmark: false,
+            retain_samples: false,
         }
     }
 }
@@ -30,10 +108,32 @@ impl Default for PerfConfig {
 pub struct PerfScenarioResult {
     pub name: String,
     pub avg_ms: f64,
+    pub p50_ms: f64,
     pub p95_ms: f64,
+    pub p99_ms: f64,
     pub min_ms: f64,
     pub max_ms: f64,
+    pub stddev_ms: f64,
+    pub mild_outliers: u32,
+    pub severe_outliers: u32,
     pub iterations: u32,
+    /// Number of `DrawBatch`es `batch::batch_renderables` coalesced the scenario's renderables
+    /// into. Only populated for `renderable_batching`; `None` elsewhere.
+    pub batch_count: Option<u32>,
+    /// `renderables.len() / batch_count`, i.e. how many entities share a single instanced draw on
+    /// average. Only populated for `renderable_batching`; `None` elsewhere.
+    pub mean_instances_per_batch: Option<f64>,
+    /// Entities `cull::cull_world` kept as visible. Only populated for `scene_cull`; `None`
+    /// elsewhere.
+    pub culled_visible_count: Option<u32>,
+    /// `culled_visible_count / total_count`, i.e. the fraction of the scattered scene the
+    /// viewport-sized `CameraBounds` actually kept. Only populated for `scene_cull`; `None`
+    /// elsewhere.
+    pub cull_ratio: Option<f64>,
+    /// Raw per-iteration timings backing this summary, kept only when `PerfConfig::retain_samples`
+    /// is set. `compare_reports` needs these to run a Mann–Whitney U test; without them it falls
+    /// back to a plain ratio comparison.
+    pub samples: Option<Vec<f64>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -44,82 +144,688 @@ pub struct PerfReport {
     pub scenarios: Vec<PerfScenarioResult>,
 }
 
+/// Gating thresholds for `compare_reports`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CompareOptions {
+    /// Mann–Whitney U p-value below which the candidate is considered stochastically slower,
+    /// rather than just noisier. Only consulted when both sides retained raw `samples`.
+    pub alpha: f64,
+    /// Minimum relative change (e.g. `0.05` for 5%) before a verdict is `Regressed`/`Improved`
+    /// rather than `Unchanged`, applied on top of (not instead of) the significance test.
+    pub relative_threshold: f64,
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        Self {
+            alpha: 0.05,
+            relative_threshold: 0.05,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegressionVerdict {
+    Improved,
+    Regressed,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioComparison {
+    pub name: String,
+    pub verdict: RegressionVerdict,
+    /// Relative change the verdict was based on (candidate vs. baseline): the median delta when a
+    /// Mann–Whitney test ran, otherwise the larger-magnitude of the `p95_ms`/`avg_ms` ratio
+    /// deltas. `0.1` means the candidate is 10% slower.
+    pub effect_size: f64,
+    /// Mann–Whitney U two-sided p-value, or `None` when either side lacked raw `samples` and the
+    /// ratio fallback was used instead.
+    pub p_value: Option<f64>,
+    pub baseline_avg_ms: f64,
+    pub candidate_avg_ms: f64,
+}
+
+/// Matches `baseline` and `candidate` scenarios by name and judges each pair for a regression.
+/// Scenarios present in only one report are skipped — there's nothing to compare them against.
+pub fn compare_reports(
+    baseline: &PerfReport,
+    candidate: &PerfReport,
+    opts: CompareOptions,
+) -> Vec<ScenarioComparison> {
+    baseline
+        .scenarios
+        .iter()
+        .filter_map(|base| {
+            candidate
+                .scenarios
+                .iter()
+                .find(|cand| cand.name == base.name)
+                .map(|cand| compare_scenario(base, cand, &opts))
+        })
+        .collect()
+}
+
+fn compare_scenario(
+    baseline: &PerfScenarioResult,
+    candidate: &PerfScenarioResult,
+    opts: &CompareOptions,
+) -> ScenarioComparison {
+    let (effect_size, p_value) = match (&baseline.samples, &candidate.samples) {
+        (Some(base_samples), Some(cand_samples))
+            if base_samples.len() >= 2 && cand_samples.len() >= 2 =>
+        {
+            let p = mann_whitney_p(base_samples, cand_samples);
+            let baseline_median = median(base_samples);
+            let candidate_median = median(cand_samples);
+            let delta = relative_delta(baseline_median, candidate_median);
+            (delta, Some(p))
+        }
+        _ => {
+            let p95_delta = relative_delta(baseline.p95_ms, candidate.p95_ms);
+            let avg_delta = relative_delta(baseline.avg_ms, candidate.avg_ms);
+            let delta = if p95_delta.abs() >= avg_delta.abs() {
+                p95_delta
+            } else {
+                avg_delta
+            };
+            (delta, None)
+        }
+    };
+
+    let significant = p_value.map(|p| p < opts.alpha).unwrap_or(true);
+    let verdict = if significant && effect_size > opts.relative_threshold {
+        RegressionVerdict::Regressed
+    } else if significant && effect_size < -opts.relative_threshold {
+        RegressionVerdict::Improved
+    } else {
+        RegressionVerdict::Unchanged
+    };
+
+    ScenarioComparison {
+        name: baseline.name.clone(),
+        verdict,
+        effect_size,
+        p_value,
+        baseline_avg_ms: baseline.avg_ms,
+        candidate_avg_ms: candidate.avg_ms,
+    }
+}
+
+fn relative_delta(baseline: f64, candidate: f64) -> f64 {
+    if baseline > 0.0 {
+        candidate / baseline - 1.0
+    } else {
+        0.0
+    }
+}
+
+fn median(samples: &[f64]) -> f64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    percentile(&sorted, 0.5)
+}
+
+/// Two-sided Mann–Whitney U test p-value via the normal approximation: ranks the pooled samples
+/// (averaging ranks across ties), sums the candidate group's ranks to derive its U statistic, then
+/// computes a z-score against the null distribution's mean `n1*n2/2` and stddev
+/// `sqrt(n1*n2*(n1+n2+1)/12)`. No continuity or tie-variance correction — the engine's informal
+/// CI gate doesn't need that precision.
+fn mann_whitney_p(baseline: &[f64], candidate: &[f64]) -> f64 {
+    let n1 = baseline.len();
+    let n2 = candidate.len();
+
+    let mut pooled: Vec<(f64, bool)> = baseline
+        .iter()
+        .map(|&v| (v, false))
+        .chain(candidate.iter().map(|&v| (v, true)))
+        .collect();
+    pooled.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut ranks = vec![0.0_f64; pooled.len()];
+    let mut i = 0;
+    while i < pooled.len() {
+        let mut j = i;
+        while j + 1 < pooled.len() && pooled[j + 1].0 == pooled[i].0 {
+            j += 1;
+        }
+        let average_rank = ((i + 1) + (j + 1)) as f64 / 2.0;
+        for rank in ranks.iter_mut().take(j + 1).skip(i) {
+            *rank = average_rank;
+        }
+        i = j + 1;
+    }
+
+    let candidate_rank_sum: f64 = pooled
+        .iter()
+        .zip(ranks.iter())
+        .filter(|((_, is_candidate), _)| *is_candidate)
+        .map(|(_, &rank)| rank)
+        .sum();
+
+    let u_candidate = candidate_rank_sum - (n2 * (n2 + 1)) as f64 / 2.0;
+    let mean_u = (n1 * n2) as f64 / 2.0;
+    let std_u = ((n1 * n2 * (n1 + n2 + 1)) as f64 / 12.0).sqrt();
+
+    if std_u == 0.0 {
+        return 1.0;
+    }
+
+    let z = (u_candidate - mean_u) / std_u;
+    2.0 * (1.0 - standard_normal_cdf(z.abs()))
+}
+
+/// Abramowitz & Stegun formula 7.1.26 approximation of the standard normal CDF (max error
+/// `1.5e-7`), since neither `std` nor this crate's dependencies expose `erf`.
+fn standard_normal_cdf(x: f64) -> f64 {
+    let a1 = 0.254829592;
+    let a2 = -0.284496736;
+    let a3 = 1.421413741;
+    let a4 = -1.453152027;
+    let a5 = 1.061405429;
+    let p = 0.3275911;
+
+    let t = 1.0 / (1.0 + p * x / std::f64::consts::SQRT_2);
+    let erf = 1.0 - (((((a5 * t + a4) * t) + a3) * t + a2) * t + a1) * t * (-(x * x) / 2.0).exp();
+    0.5 * (1.0 + erf)
+}
+
 pub fn run_performance_baseline(config: PerfConfig) -> PerfReport {
+    run_performance_baseline_with_progress(config, |_event| {})
+}
+
+/// Same as `run_performance_baseline`, but invokes `on_progress` on a throttled cadence during
+/// both the warmup and measurement phases of each scenario so long runs can show a live indicator.
+pub fn run_performance_baseline_with_progress(
+    config: PerfConfig,
+    mut on_progress: impl FnMut(ProgressEvent),
+) -> PerfReport {
     let sprite_world = build_sprite_world(config.sprite_count);
     let texture_map = build_texture_map(config.sprite_count);
+    let atlas_rects = build_atlas_rects(&texture_map);
 
-    let sprite_samples = benchmark_samples(config.warmup_iterations, config.iterations, || {
-        let _renderables = build_renderables_from_world(&sprite_world, &texture_map);
-    });
+    let sprite_samples = benchmark_samples(
+        "sprite_renderable_build",
+        config.warmup_iterations,
+        config.iterations,
+        || {
+            let _renderables = build_renderables_from_world(&sprite_world, &texture_map, &atlas_rects, None);
+        },
+        &mut on_progress,
+    );
 
-    let ui_samples = benchmark_samples(config.warmup_iterations, config.iterations, || {
-        let _text_commands = build_ui_text_commands(config.ui_items_per_row, config.ui_items_per_col);
-    });
+    let atlas_pack_samples = benchmark_samples(
+        "atlas_pack_build",
+        config.warmup_iterations,
+        config.iterations,
+        || {
+            let _packer = pack_distinct_sprite_textures(config.sprite_count);
+        },
+        &mut on_progress,
+    );
 
-    let scene_samples = benchmark_samples(config.warmup_iterations, config.iterations, || {
-        let _ = run_scene_construct_destruct_cycle(config.scene_entity_count);
-    });
+    let renderables_for_batching = build_renderables_from_world(&sprite_world, &texture_map, &atlas_rects, None);
+    let batching_samples = benchmark_samples(
+        "renderable_batching",
+        config.warmup_iterations,
+        config.iterations,
+        || {
+            let _ = batch::batch_renderables(&renderables_for_batching);
+        },
+        &mut on_progress,
+    );
+    let (sample_batches, _) = batch::batch_renderables(&renderables_for_batching);
+    let batch_count = sample_batches.len() as u32;
+    let mean_instances_per_batch = if batch_count > 0 {
+        renderables_for_batching.len() as f64 / batch_count as f64
+    } else {
+        0.0
+    };
 
-    let generated_unix_epoch_sec = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .map(|duration| duration.as_secs())
-        .unwrap_or(0);
+    let pack_samples = benchmark_samples(
+        "instance_pack_std140",
+        config.warmup_iterations,
+        config.iterations,
+        || {
+            let _bytes = gpu::pack_instances_std140(&renderables_for_batching);
+        },
+        &mut on_progress,
+    );
+
+    let ui_samples = benchmark_samples(
+        "ui_text_command_build",
+        config.warmup_iterations,
+        config.iterations,
+        || {
+            let _text_commands =
+                build_ui_text_commands(config.ui_items_per_row, config.ui_items_per_col);
+        },
+        &mut on_progress,
+    );
+
+    let scattered_world = build_scattered_world(config.scene_entity_count);
+    let viewport = cull::CameraBounds {
+        min: ffi::Vec2 { x: -640.0, y: -360.0 },
+        max: ffi::Vec2 { x: 640.0, y: 360.0 },
+        margin: 0.0,
+    };
+    let cull_samples = benchmark_samples(
+        "scene_cull",
+        config.warmup_iterations,
+        config.iterations,
+        || {
+            let _visible = cull::cull_world(&scattered_world, &viewport);
+        },
+        &mut on_progress,
+    );
+    let total_count = scattered_world
+        .archetypes
+        .iter()
+        .map(|archetype| archetype.entity_count)
+        .sum::<usize>() as u32;
+    let culled_visible_count = cull::cull_world(&scattered_world, &viewport).len() as u32;
+    let cull_ratio = if total_count > 0 {
+        culled_visible_count as f64 / total_count as f64
+    } else {
+        0.0
+    };
+
+    let scene_samples = benchmark_samples(
+        "scene_construct_destruct",
+        config.warmup_iterations,
+        config.iterations,
+        || {
+            let _ = run_scene_construct_destruct_cycle(config.scene_entity_count);
+        },
+        &mut on_progress,
+    );
+
+    let life_board = LifeBoard::seeded(
+        config.life_grid_width,
+        config.life_grid_height,
+        config.life_seed,
+    );
+    let life_samples = benchmark_samples(
+        "game_of_life_step",
+        config.warmup_iterations,
+        config.iterations,
+        || {
+            let mut board = life_board.clone();
+            for _ in 0..config.life_generations {
+                board.step();
+            }
+        },
+        &mut on_progress,
+    );
+
+    let generated_unix_epoch_sec = generated_unix_epoch_sec();
+
+    let keep_outliers = config.keep_outliers;
+    let retain_samples = config.retain_samples;
+    let mut scenarios = vec![
+        summarize_samples("sprite_renderable_build", &sprite_samples, keep_outliers, retain_samples),
+        summarize_samples("atlas_pack_build", &atlas_pack_samples, keep_outliers, retain_samples),
+        PerfScenarioResult {
+            batch_count: Some(batch_count),
+            mean_instances_per_batch: Some(mean_instances_per_batch),
+            ..summarize_samples("renderable_batching", &batching_samples, keep_outliers, retain_samples)
+        },
+        summarize_samples("instance_pack_std140", &pack_samples, keep_outliers, retain_samples),
+        summarize_samples("ui_text_command_build", &ui_samples, keep_outliers, retain_samples),
+        PerfScenarioResult {
+            culled_visible_count: Some(culled_visible_count),
+            cull_ratio: Some(cull_ratio),
+            ..summarize_samples("scene_cull", &cull_samples, keep_outliers, retain_samples)
+        },
+        summarize_samples("scene_construct_destruct", &scene_samples, keep_outliers, retain_samples),
+        summarize_samples("game_of_life_step", &life_samples, keep_outliers, retain_samples),
+    ];
+
+    if config.gpu_benchmark {
+        scenarios.push(gpu_bench::run_gpu_draw_call_scenario(&config));
+    }
 
     PerfReport {
         schema_version: 1,
         generated_unix_epoch_sec,
         config,
-        scenarios: vec![
-            summarize_samples("sprite_renderable_build", &sprite_samples),
-            summarize_samples("ui_text_command_build", &ui_samples),
-            summarize_samples("scene_construct_destruct", &scene_samples),
-        ],
+        scenarios,
     }
 }
 
-fn benchmark_samples<F>(warmup_iterations: u32, iterations: u32, mut func: F) -> Vec<f64>
+/// Opt-in GPU draw-call scenario, gated behind the `gpu_bench` cargo feature so the default
+/// build (and CI containers without a GPU) never pull in `wgpu`/`pollster`.
+#[cfg(feature = "gpu_bench")]
+mod gpu_bench {
+    use super::{now_ms, PerfConfig, PerfScenarioResult};
+
+    const SCENARIO_NAME: &str = "gpu_draw_call";
+
+    /// Stands up a headless wgpu device and, each iteration, records and submits a clear-only
+    /// render pass to an offscreen texture, measuring from encoding until `device.poll(Wait)`
+    /// returns. No pipeline is bound, no instance data is uploaded and nothing is mapped, so
+    /// `sprite_count` does not change the work done.
+    /// Returns an "unavailable" scenario (zero iterations) instead of panicking when no adapter
+    /// can be created, so the binary still runs in GPU-less CI containers.
+    pub fn run_gpu_draw_call_scenario(config: &PerfConfig) -> PerfScenarioResult {
+        match pollster::block_on(try_run(config)) {
+            Some(samples) => {
+                super::summarize_samples(SCENARIO_NAME, &samples, config.keep_outliers, config.retain_samples)
+            }
+            None => unavailable_scenario(),
+        }
+    }
+
+    fn unavailable_scenario() -> PerfScenarioResult {
+        PerfScenarioResult {
+            name: format!("{SCENARIO_NAME}_unavailable"),
+            avg_ms: 0.0,
+            p50_ms: 0.0,
+            p95_ms: 0.0,
+            p99_ms: 0.0,
+            min_ms: 0.0,
+            max_ms: 0.0,
+            stddev_ms: 0.0,
+            mild_outliers: 0,
+            severe_outliers: 0,
+            iterations: 0,
+            batch_count: None,
+            mean_instances_per_batch: None,
+            culled_visible_count: None,
+            cull_ratio: None,
+            samples: None,
+        }
+    }
+
+    async fn try_run(config: &PerfConfig) -> Option<Vec<f64>> {
+        let instance = wgpu::Instance::default();
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                compatible_surface: None,
+                force_fallback_adapter: false,
+            })
+            .await?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default(), None)
+            .await
+            .ok()?;
+
+        const TEXTURE_SIZE: u32 = 256;
+        let target = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("miyabi_perf_offscreen"),
+            size: wgpu::Extent3d {
+                width: TEXTURE_SIZE,
+                height: TEXTURE_SIZE,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8UnormSrgb,
+            usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            view_formats: &[],
+        });
+        let target_view = target.create_view(&wgpu::TextureViewDescriptor::default());
+
+        // Derived from `--sprite-count`, but no instances are uploaded or drawn with it.
+        let instance_count = config.sprite_count.max(1) as u32;
+
+        let run_iteration = |device: &wgpu::Device, queue: &wgpu::Queue| -> f64 {
+            let started_at = now_ms();
+            let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("miyabi_perf_encoder"),
+            });
+            {
+                let mut render_pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("miyabi_perf_pass"),
+                    color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                        view: &target_view,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                            store: wgpu::StoreOp::Store,
+                        },
+                    })],
+                    depth_stencil_attachment: None,
+                    timestamp_writes: None,
+                    occlusion_query_set: None,
+                });
+                // No pipeline is bound and nothing is drawn: this measures clear + submit +
+                // poll overhead only.
+                let _ = &render_pass;
+                let _ = instance_count;
+            }
+            queue.submit(Some(encoder.finish()));
+            started_at
+        };
+
+        for _ in 0..config.warmup_iterations {
+            run_iteration(&device, &queue);
+            device.poll(wgpu::Maintain::Wait);
+        }
+
+        let mut samples = Vec::with_capacity(config.iterations as usize);
+        for _ in 0..config.iterations {
+            let started_at = run_iteration(&device, &queue);
+            device.poll(wgpu::Maintain::Wait);
+            samples.push(now_ms() - started_at);
+        }
+
+        Some(samples)
+    }
+}
+
+#[cfg(not(feature = "gpu_bench"))]
+mod gpu_bench {
+    use super::{PerfConfig, PerfScenarioResult};
+
+    /// Without the `gpu_bench` feature there is no wgpu dependency to stand up a device, so the
+    /// scenario always reports as unavailable rather than silently being omitted.
+    pub fn run_gpu_draw_call_scenario(_config: &PerfConfig) -> PerfScenarioResult {
+        PerfScenarioResult {
+            name: "gpu_draw_call_unavailable".to_string(),
+            avg_ms: 0.0,
+            p50_ms: 0.0,
+            p95_ms: 0.0,
+            p99_ms: 0.0,
+            min_ms: 0.0,
+            max_ms: 0.0,
+            stddev_ms: 0.0,
+            mild_outliers: 0,
+            severe_outliers: 0,
+            iterations: 0,
+            batch_count: None,
+            mean_instances_per_batch: None,
+            culled_visible_count: None,
+            cull_ratio: None,
+            samples: None,
+        }
+    }
+}
+
+fn benchmark_samples<F>(
+    scenario: &str,
+    warmup_iterations: u32,
+    iterations: u32,
+    mut func: F,
+    on_progress: &mut impl FnMut(ProgressEvent),
+) -> Vec<f64>
 where
     F: FnMut(),
 {
-    for _ in 0..warmup_iterations {
+    let phase_started_at = now_ms();
+    let mut last_reported_at = phase_started_at - PROGRESS_REPORT_INTERVAL_MS;
+    for i in 0..warmup_iterations {
         func();
+        last_reported_at = maybe_report_progress(
+            scenario,
+            BenchmarkPhase::Warmup,
+            i + 1,
+            warmup_iterations,
+            phase_started_at,
+            last_reported_at,
+            on_progress,
+        );
     }
 
+    let phase_started_at = now_ms();
+    let mut last_reported_at = phase_started_at - PROGRESS_REPORT_INTERVAL_MS;
     let mut samples = Vec::with_capacity(iterations as usize);
-    for _ in 0..iterations {
-        let started_at = Instant::now();
+    for i in 0..iterations {
+        let started_at = now_ms();
         func();
-        samples.push(started_at.elapsed().as_secs_f64() * 1000.0);
+        samples.push(now_ms() - started_at);
+        last_reported_at = maybe_report_progress(
+            scenario,
+            BenchmarkPhase::Measure,
+            i + 1,
+            iterations,
+            phase_started_at,
+            last_reported_at,
+            on_progress,
+        );
     }
     samples
 }
 
-fn summarize_samples(name: &str, samples: &[f64]) -> PerfScenarioResult {
-    let mut sorted = samples.to_vec();
-    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+/// Reports progress at most once per `PROGRESS_REPORT_INTERVAL_MS`, always reporting the final
+/// iteration of the phase so callers see a completed progress bar. Returns the millisecond
+/// timestamp the callback was last invoked at (unchanged if it was skipped this call).
+fn maybe_report_progress(
+    scenario: &str,
+    phase: BenchmarkPhase,
+    current: u32,
+    total: u32,
+    phase_started_at: f64,
+    last_reported_at: f64,
+    on_progress: &mut impl FnMut(ProgressEvent),
+) -> f64 {
+    let now = now_ms();
+    let is_final = current >= total;
+    if !is_final && now - last_reported_at < PROGRESS_REPORT_INTERVAL_MS {
+        return last_reported_at;
+    }
 
-    let avg_ms = if samples.is_empty() {
-        0.0
+    let elapsed_ms = now - phase_started_at;
+    let estimated_remaining = if current == 0 {
+        Duration::ZERO
     } else {
-        samples.iter().sum::<f64>() / samples.len() as f64
+        let per_iteration_ms = elapsed_ms / current as f64;
+        let remaining_ms = (per_iteration_ms * total.saturating_sub(current) as f64).max(0.0);
+        Duration::from_secs_f64(remaining_ms / 1000.0)
     };
+
+    on_progress(ProgressEvent {
+        scenario,
+        phase,
+        current,
+        total,
+        estimated_remaining,
+    });
+    now
+}
+
+fn summarize_samples(
+    name: &str,
+    samples: &[f64],
+    keep_outliers: bool,
+    retain_samples: bool,
+) -> PerfScenarioResult {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
     let min_ms = sorted.first().copied().unwrap_or(0.0);
     let max_ms = sorted.last().copied().unwrap_or(0.0);
-    let p95_ms = if sorted.is_empty() {
-        0.0
+
+    let (mild_outliers, severe_outliers, inliers) = if keep_outliers || sorted.len() < 4 {
+        (0, 0, sorted.clone())
     } else {
-        let index = ((sorted.len() as f64 * 0.95).ceil() as usize).saturating_sub(1);
-        sorted[index]
+        classify_outliers(&sorted)
     };
 
+    let avg_ms = mean(&inliers);
+    let stddev_ms = stddev(&inliers, avg_ms);
+
     PerfScenarioResult {
         name: name.to_string(),
         avg_ms,
-        p95_ms,
+        p50_ms: percentile(&sorted, 0.50),
+        p95_ms: percentile(&sorted, 0.95),
+        p99_ms: percentile(&sorted, 0.99),
         min_ms,
         max_ms,
+        stddev_ms,
+        mild_outliers,
+        severe_outliers,
         iterations: samples.len() as u32,
+        batch_count: None,
+        mean_instances_per_batch: None,
+        culled_visible_count: None,
+        cull_ratio: None,
+        samples: if retain_samples { Some(samples.to_vec()) } else { None },
+    }
+}
+
+/// Nearest-rank percentile over an already-sorted sample set.
+fn percentile(sorted: &[f64], fraction: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let index = ((sorted.len() as f64 * fraction).ceil() as usize)
+        .saturating_sub(1)
+        .min(sorted.len() - 1);
+    sorted[index]
+}
+
+fn mean(samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        0.0
+    } else {
+        samples.iter().sum::<f64>() / samples.len() as f64
+    }
+}
+
+fn stddev(samples: &[f64], mean_ms: f64) -> f64 {
+    if samples.len() < 2 {
+        return 0.0;
+    }
+    let variance = samples
+        .iter()
+        .map(|sample| (sample - mean_ms).powi(2))
+        .sum::<f64>()
+        / (samples.len() - 1) as f64;
+    variance.sqrt()
+}
+
+/// Classifies samples using Tukey fences (Q1 - 1.5*IQR / Q3 + 1.5*IQR for mild outliers,
+/// 3*IQR for severe ones) and returns (mild_count, severe_count, inlier_samples).
+/// `sorted` must already be sorted ascending.
+fn classify_outliers(sorted: &[f64]) -> (u32, u32, Vec<f64>) {
+    let q1 = percentile(sorted, 0.25);
+    let q3 = percentile(sorted, 0.75);
+    let iqr = q3 - q1;
+
+    let mild_low = q1 - 1.5 * iqr;
+    let mild_high = q3 + 1.5 * iqr;
+    let severe_low = q1 - 3.0 * iqr;
+    let severe_high = q3 + 3.0 * iqr;
+
+    let mut mild_outliers = 0u32;
+    let mut severe_outliers = 0u32;
+    let mut inliers = Vec::with_capacity(sorted.len());
+
+    for &sample in sorted {
+        if sample < severe_low || sample > severe_high {
+            severe_outliers += 1;
+        } else if sample < mild_low || sample > mild_high {
+            mild_outliers += 1;
+        } else {
+            inliers.push(sample);
+        }
     }
+
+    (mild_outliers, severe_outliers, inliers)
 }
 
 fn build_sprite_world(sprite_count: usize) -> InternalWorld {
@@ -148,6 +854,34 @@ fn build_sprite_world(sprite_count: usize) -> InternalWorld {
     world
 }
 
+/// Distance between adjacent sprites in `build_scattered_world`'s grid — large enough that only a
+/// small fraction of `entity_count` sprites fall inside `scene_cull`'s viewport-sized box.
+const SCATTER_STEP: f32 = 400.0;
+
+/// Spreads `entity_count` sprites over a grid far larger than any one viewport, for the
+/// `scene_cull` scenario to cull down to a realistic visible fraction instead of keeping
+/// everything (as `build_sprite_world`'s tight grid would).
+fn build_scattered_world(entity_count: usize) -> InternalWorld {
+    let mut world = InternalWorld::new();
+    let cols = (entity_count as f64).sqrt().ceil().max(1.0) as usize;
+    for i in 0..entity_count {
+        let col = (i % cols) as f32;
+        let row = (i / cols) as f32;
+        let x = (col - cols as f32 / 2.0) * SCATTER_STEP;
+        let y = (row - cols as f32 / 2.0) * SCATTER_STEP;
+        world.spawn((
+            ffi::Transform {
+                position: ffi::Vec3 { x, y, z: 0.0 },
+                rotation: ffi::Vec3 { x: 0.0, y: 0.0, z: 0.0 },
+                scale: ffi::Vec3 { x: 10.0, y: 10.0, z: 1.0 },
+            },
+            Material { texture_handle: 1 },
+            Sprite,
+        ));
+    }
+    world
+}
+
 fn build_texture_map(sprite_count: usize) -> HashMap<u32, u32> {
     let mut texture_map = HashMap::new();
     // 参照が常に成立する状態を作り、build_renderables 相当の処理だけを計測する。
@@ -155,12 +889,39 @@ fn build_texture_map(sprite_count: usize) -> HashMap<u32, u32> {
     texture_map
 }
 
+/// Fixed sub-texture size used for benchmark sprites; real sprite sheets vary per asset, but a
+/// single constant is enough to exercise the packer's shelf/layer-spill logic under load.
+const BENCH_SPRITE_TILE: u32 = 16;
+
+/// Packs one `BENCH_SPRITE_TILE`-square tile per distinct texture id already present in
+/// `texture_map`, for feeding `build_renderables_from_world` a real (if trivial) atlas.
+fn build_atlas_rects(texture_map: &HashMap<u32, u32>) -> HashMap<u32, atlas::AtlasRect> {
+    let mut packer = atlas::AtlasPacker::new();
+    for &texture_id in texture_map.values() {
+        packer.pack(texture_id, BENCH_SPRITE_TILE, BENCH_SPRITE_TILE);
+    }
+    packer.rects().clone()
+}
+
+/// Packs `sprite_count` distinct `BENCH_SPRITE_TILE`-square textures (ids `0..sprite_count`) into
+/// a fresh packer, for the `atlas_pack_build` scenario's "many small unique textures" case, as
+/// opposed to `build_atlas_rects`'s "handful of ids reused across every sprite" case.
+fn pack_distinct_sprite_textures(sprite_count: usize) -> atlas::AtlasPacker {
+    let mut packer = atlas::AtlasPacker::new();
+    for id in 0..sprite_count as u32 {
+        packer.pack(id, BENCH_SPRITE_TILE, BENCH_SPRITE_TILE);
+    }
+    packer
+}
+
 fn build_renderables_from_world(
     world: &InternalWorld,
     texture_map: &HashMap<u32, u32>,
+    atlas_rects: &HashMap<u32, atlas::AtlasRect>,
+    visible: Option<&HashSet<cull::EntityIndex>>,
 ) -> Vec<ffi::RenderableObject> {
     let mut renderables = Vec::new();
-    for archetype in &world.archetypes {
+    for (archetype_idx, archetype) in world.archetypes.iter().enumerate() {
         let has_transform = archetype.types.contains(&ComponentType::Transform);
         let has_material = archetype.types.contains(&ComponentType::Material);
         if !has_transform || !has_material {
@@ -181,16 +942,38 @@ fn build_renderables_from_world(
             continue;
         };
 
-        for (transform, material) in transforms.iter().zip(materials.iter()) {
+        for (row, (transform, material)) in transforms.iter().zip(materials.iter()).enumerate() {
+            if let Some(visible) = visible {
+                let index = cull::EntityIndex { archetype: archetype_idx, row };
+                if !visible.contains(&index) {
+                    continue;
+                }
+            }
             let texture_id = texture_map
                 .get(&material.texture_handle)
                 .copied()
                 .unwrap_or(0);
+            let (atlas_layer, uv_min, uv_max) = match atlas_rects.get(&texture_id) {
+                Some(rect) => (
+                    rect.layer,
+                    ffi::Vec2 { x: rect.u0, y: rect.v0 },
+                    ffi::Vec2 { x: rect.u1, y: rect.v1 },
+                ),
+                None => (
+                    0,
+                    ffi::Vec2 { x: 0.0, y: 0.0 },
+                    ffi::Vec2 { x: 1.0, y: 1.0 },
+                ),
+            };
             renderables.push(ffi::RenderableObject {
                 transform: *transform,
                 mesh_id: 1,
                 material_id: 1,
                 texture_id,
+                color: ffi::Vec4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 },
+                atlas_layer,
+                uv_min,
+                uv_max,
             });
         }
     }
@@ -224,6 +1007,87 @@ fn build_ui_text_commands(items_per_row: usize, items_per_col: usize) -> Vec<ffi
     text_commands
 }
 
+/// A bit-packed, toroidal Conway's Game of Life board used as a deterministic CPU-bound
+/// benchmark: given the same seed and dimensions, `step()` always produces the same sequence of
+/// generations, so timings are comparable across machines and commits.
+#[derive(Clone)]
+struct LifeBoard {
+    width: usize,
+    height: usize,
+    /// One bit per cell, row-major, packed into `u64` words.
+    cells: Vec<u64>,
+    scratch: Vec<u64>,
+}
+
+impl LifeBoard {
+    fn words_per_row(width: usize) -> usize {
+        width.div_ceil(64)
+    }
+
+    fn seeded(width: usize, height: usize, seed: u64) -> Self {
+        let words_per_row = Self::words_per_row(width);
+        let mut cells = vec![0u64; words_per_row * height];
+        let mut rng_state = if seed == 0 { 0x2545_F491 } else { seed };
+
+        for row in cells.iter_mut() {
+            // xorshift64: deterministic given `seed`, so the same board is reproduced every run.
+            rng_state ^= rng_state << 13;
+            rng_state ^= rng_state >> 7;
+            rng_state ^= rng_state << 17;
+            *row = rng_state;
+        }
+
+        let scratch = vec![0u64; words_per_row * height];
+        Self {
+            width,
+            height,
+            cells,
+            scratch,
+        }
+    }
+
+    fn get(&self, x: isize, y: isize) -> u64 {
+        let width = self.width as isize;
+        let height = self.height as isize;
+        let x = x.rem_euclid(width) as usize;
+        let y = y.rem_euclid(height) as usize;
+        let words_per_row = Self::words_per_row(self.width);
+        let word = self.cells[y * words_per_row + x / 64];
+        (word >> (x % 64)) & 1
+    }
+
+    /// Advances the board by one generation into `scratch`, then swaps the buffers so the next
+    /// call reads the freshly computed state without mutating cells mid-scan.
+    fn step(&mut self) {
+        let words_per_row = Self::words_per_row(self.width);
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let mut live_neighbors = 0u64;
+                for dy in [-1isize, 0, 1] {
+                    for dx in [-1isize, 0, 1] {
+                        if dx == 0 && dy == 0 {
+                            continue;
+                        }
+                        live_neighbors += self.get(x as isize + dx, y as isize + dy);
+                    }
+                }
+
+                let alive = self.get(x as isize, y as isize) == 1;
+                let next_alive = live_neighbors == 3 || (alive && live_neighbors == 2);
+
+                let word_idx = y * words_per_row + x / 64;
+                let bit = x % 64;
+                if next_alive {
+                    self.scratch[word_idx] |= 1 << bit;
+                } else {
+                    self.scratch[word_idx] &= !(1 << bit);
+                }
+            }
+        }
+        std::mem::swap(&mut self.cells, &mut self.scratch);
+    }
+}
+
 fn run_scene_construct_destruct_cycle(entity_count: usize) -> usize {
     let mut world = InternalWorld::new();
     for i in 0..entity_count {
@@ -0,0 +1,283 @@
+//! Generic query API over `InternalWorld`'s archetype storage.
+//!
+//! `query::<(A, B)>()` scans `archetypes` for every one whose `types` superset the requested
+//! `ComponentType`s and yields `(Entity, &A, &B)` row by row; `query_mut` yields `&mut` instead.
+//! Supports the same 1-6 arities as `ComponentBundle`.
+
+use crate::{Archetype, Component, ComponentType, Entity, InternalWorld};
+use std::marker::PhantomData;
+
+/// Locates the `Entity` occupying `(archetype_idx, row)` by scanning `entities`. Same O(n) cost
+/// as the rest of this ECS's bookkeeping (e.g. `clear_entities_of_component`); fine at the entity
+/// counts this engine deals with, but the first thing to replace with a per-archetype reverse
+/// index if query iteration ever shows up in a profile.
+fn entity_at(world: &InternalWorld, archetype_idx: usize, row: usize) -> Option<Entity> {
+    world
+        .entities
+        .iter()
+        .find(|(_, &(idx, r))| idx == archetype_idx && r == row)
+        .map(|(&entity, _)| entity)
+}
+
+fn archetype_matches(archetype: &Archetype, types: &[ComponentType]) -> bool {
+    types.iter().all(|t| archetype.types.contains(t))
+}
+
+/// A tuple of components fetched by shared reference.
+pub trait QueryRef<'w> {
+    type Item;
+    fn component_types() -> Vec<ComponentType>;
+    fn fetch_row(archetype: &'w Archetype, row: usize) -> Self::Item;
+}
+
+/// A tuple of components fetched by mutable reference. Implementations borrow each
+/// `ComponentType`'s storage vec out of the archetype's `HashMap` independently, so a query over
+/// distinct component types never aliases the same vec twice.
+pub trait QueryMut<'w> {
+    type Item;
+    fn component_types() -> Vec<ComponentType>;
+    fn fetch_row_mut(archetype: &'w mut Archetype, row: usize) -> Self::Item;
+}
+
+fn storage_ref<'w, T: Component + 'static>(archetype: &'w Archetype, row: usize) -> &'w T {
+    archetype
+        .storage
+        .get(&T::COMPONENT_TYPE)
+        .and_then(|storage| storage.downcast_ref::<Vec<T>>())
+        .and_then(|vec| vec.get(row))
+        .expect("archetype matched query but is missing the expected component storage/row")
+}
+
+/// Borrows `T`'s storage vec out of the archetype by raw pointer so a multi-component `QueryMut`
+/// tuple can hold several simultaneous `&mut` borrows into the same `HashMap<ComponentType, _>`
+/// without aliasing: each component type owns a disjoint entry, so this is sound as long as a
+/// query tuple never repeats a `ComponentType`.
+fn storage_mut<'w, T: Component + 'static>(archetype: &mut Archetype, row: usize) -> &'w mut T {
+    let vec_ptr = archetype
+        .storage
+        .get_mut(&T::COMPONENT_TYPE)
+        .and_then(|storage| storage.downcast_mut::<Vec<T>>())
+        .expect("archetype matched query but is missing the expected component storage") as *mut Vec<T>;
+    unsafe {
+        (*vec_ptr)
+            .get_mut(row)
+            .expect("archetype matched query but row is out of bounds")
+    }
+}
+
+macro_rules! impl_query_ref {
+    ($($t:ident),+) => {
+        impl<'w, $($t: Component + 'static),+> QueryRef<'w> for ($($t,)+) {
+            type Item = ($(&'w $t,)+);
+
+            fn component_types() -> Vec<ComponentType> {
+                vec![$($t::COMPONENT_TYPE),+]
+            }
+
+            fn fetch_row(archetype: &'w Archetype, row: usize) -> Self::Item {
+                ($(storage_ref::<$t>(archetype, row),)+)
+            }
+        }
+
+        impl<'w, $($t: Component + 'static),+> QueryMut<'w> for ($($t,)+) {
+            type Item = ($(&'w mut $t,)+);
+
+            fn component_types() -> Vec<ComponentType> {
+                vec![$($t::COMPONENT_TYPE),+]
+            }
+
+            fn fetch_row_mut(archetype: &'w mut Archetype, row: usize) -> Self::Item {
+                ($(storage_mut::<$t>(archetype, row),)+)
+            }
+        }
+    };
+}
+
+impl_query_ref!(A);
+impl_query_ref!(A, B);
+impl_query_ref!(A, B, C);
+impl_query_ref!(A, B, C, D);
+impl_query_ref!(A, B, C, D, E);
+impl_query_ref!(A, B, C, D, E, F);
+
+pub struct QueryIter<'w, Q: QueryRef<'w>> {
+    world: &'w InternalWorld,
+    types: Vec<ComponentType>,
+    archetype_idx: usize,
+    row: usize,
+    _marker: PhantomData<Q>,
+}
+
+impl<'w, Q: QueryRef<'w>> Iterator for QueryIter<'w, Q> {
+    type Item = (Entity, Q::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let archetype = self.world.archetypes.get(self.archetype_idx)?;
+            if !archetype_matches(archetype, &self.types) || self.row >= archetype.entity_count {
+                self.archetype_idx += 1;
+                self.row = 0;
+                continue;
+            }
+
+            let row = self.row;
+            self.row += 1;
+            let Some(entity) = entity_at(self.world, self.archetype_idx, row) else {
+                continue;
+            };
+            return Some((entity, Q::fetch_row(archetype, row)));
+        }
+    }
+}
+
+/// Mutable counterpart of `QueryIter`. Holds a raw pointer to the world rather than a `&'w mut
+/// InternalWorld` so each call to `next()` can hand out a fresh `&'w mut` borrow scoped to a
+/// single archetype; sound because distinct iterations only ever touch distinct archetypes/rows.
+pub struct QueryIterMut<'w, Q: QueryMut<'w>> {
+    world: *mut InternalWorld,
+    types: Vec<ComponentType>,
+    archetype_idx: usize,
+    row: usize,
+    _marker: PhantomData<&'w mut Q>,
+}
+
+impl<'w, Q: QueryMut<'w>> Iterator for QueryIterMut<'w, Q> {
+    type Item = (Entity, Q::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            // SAFETY: `self.world` outlives `'w` (it came from a `&'w mut InternalWorld` in
+            // `query_mut`), and each iteration only borrows the single archetype at
+            // `archetype_idx`, never revisiting one already handed out.
+            let world: &'w mut InternalWorld = unsafe { &mut *self.world };
+            if self.archetype_idx >= world.archetypes.len() {
+                return None;
+            }
+
+            let entity = {
+                let archetype = &world.archetypes[self.archetype_idx];
+                if !archetype_matches(archetype, &self.types) || self.row >= archetype.entity_count {
+                    self.archetype_idx += 1;
+                    self.row = 0;
+                    continue;
+                }
+                entity_at(world, self.archetype_idx, self.row)
+            };
+
+            let row = self.row;
+            self.row += 1;
+            let Some(entity) = entity else {
+                continue;
+            };
+
+            let archetype: &'w mut Archetype = &mut world.archetypes[self.archetype_idx];
+            return Some((entity, Q::fetch_row_mut(archetype, row)));
+        }
+    }
+}
+
+impl InternalWorld {
+    /// Returns an iterator over every entity whose archetype carries all of `Q`'s component
+    /// types, yielding `(Entity, Q::Item)` where `Q::Item` is a tuple of shared references.
+    pub fn query<'w, Q: QueryRef<'w>>(&'w self) -> QueryIter<'w, Q> {
+        QueryIter {
+            world: self,
+            types: Q::component_types(),
+            archetype_idx: 0,
+            row: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Mutable counterpart of `query`. `Q` must not repeat a `ComponentType` (e.g. `(A, A)`),
+    /// which would otherwise alias the same storage vec through two `&mut` borrows.
+    pub fn query_mut<'w, Q: QueryMut<'w>>(&'w mut self) -> QueryIterMut<'w, Q> {
+        QueryIterMut {
+            world: self as *mut InternalWorld,
+            types: Q::component_types(),
+            archetype_idx: 0,
+            row: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Material, Player};
+
+    /// Spawns entities across three distinct archetypes so a query has to skip archetypes that
+    /// don't match and keep scanning past one that does: `Material` alone, `Material` + `Player`,
+    /// and `Player` alone.
+    fn multi_archetype_world() -> (InternalWorld, crate::Entity, crate::Entity, crate::Entity) {
+        let mut world = InternalWorld::new();
+        let material_only = world.spawn((Material { texture_handle: 1 },));
+        let material_and_player = world.spawn((Material { texture_handle: 2 }, Player));
+        let player_only = world.spawn((Player,));
+        (world, material_only, material_and_player, player_only)
+    }
+
+    #[test]
+    fn query_single_component_visits_every_matching_archetype() {
+        let (world, material_only, material_and_player, _player_only) = multi_archetype_world();
+
+        let mut seen: Vec<(crate::Entity, u32)> = world
+            .query::<(Material,)>()
+            .map(|(entity, (material,))| (entity, material.texture_handle))
+            .collect();
+        seen.sort_by_key(|(_, handle)| *handle);
+
+        assert_eq!(
+            seen,
+            vec![(material_only, 1), (material_and_player, 2)]
+        );
+    }
+
+    #[test]
+    fn query_two_components_only_matches_entities_with_both() {
+        let (world, _material_only, material_and_player, _player_only) = multi_archetype_world();
+
+        let matches: Vec<crate::Entity> = world
+            .query::<(Material, Player)>()
+            .map(|(entity, _)| entity)
+            .collect();
+
+        assert_eq!(matches, vec![material_and_player]);
+    }
+
+    #[test]
+    fn query_mut_writes_are_visible_through_a_second_query() {
+        let (mut world, material_only, material_and_player, _player_only) = multi_archetype_world();
+
+        for (_, (material,)) in world.query_mut::<(Material,)>() {
+            material.texture_handle += 100;
+        }
+
+        let mut seen: Vec<(crate::Entity, u32)> = world
+            .query::<(Material,)>()
+            .map(|(entity, (material,))| (entity, material.texture_handle))
+            .collect();
+        seen.sort_by_key(|(_, handle)| *handle);
+
+        assert_eq!(
+            seen,
+            vec![(material_only, 101), (material_and_player, 102)]
+        );
+    }
+
+    #[test]
+    fn query_mut_two_components_does_not_alias_the_same_storage() {
+        let mut world = InternalWorld::new();
+        let entity = world.spawn((Material { texture_handle: 1 }, Player));
+
+        for (_, (material, player)) in world.query_mut::<(Material, Player)>() {
+            material.texture_handle += 1;
+            let _ = player;
+        }
+
+        let (_, (material,)) = world.query::<(Material,)>().next().unwrap();
+        assert_eq!(material.texture_handle, 2);
+        assert!(world.query::<(Material, Player)>().any(|(e, _)| e == entity));
+    }
+}
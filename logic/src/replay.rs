@@ -0,0 +1,93 @@
+//! Frame-accurate input replay, recorded and played back on top of the fixed `FIXED_DT_SEC`
+//! timestep.
+//!
+//! Gameplay only ever reacts to `self.input_state`, once per tick, so a `Recorder` just has to
+//! snapshot that struct every frame; a `Player` reproduces the run by popping the same sequence
+//! back into `input_state` before the rest of `update_in_game` runs. Paired with the run's
+//! `rng::XorShift` seed (also carried on `Replay`), this reproduces an entire run bit-for-bit,
+//! which is handy for chasing down non-deterministic collision/spawn bugs or recording "ghost"
+//! runs.
+
+use crate::ffi;
+use crate::save::{self, SaveError};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A recorded run: the seed it was played with plus one `InputState` per fixed-timestep frame.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct Replay {
+    pub seed: u32,
+    pub frames: Vec<ffi::InputState>,
+}
+
+/// Appends the live `input_state` once per `GameState::InGame` frame.
+#[derive(Debug, Default)]
+pub struct Recorder {
+    seed: u32,
+    frames: Vec<ffi::InputState>,
+}
+
+impl Recorder {
+    pub fn start(seed: u32) -> Self {
+        Self {
+            seed,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, input_state: ffi::InputState) {
+        self.frames.push(input_state);
+    }
+
+    pub fn into_replay(self) -> Replay {
+        Replay {
+            seed: self.seed,
+            frames: self.frames,
+        }
+    }
+}
+
+/// Plays a `Replay` back one frame at a time, overriding `input_state` until it's exhausted.
+#[derive(Debug)]
+pub struct Player {
+    replay: Replay,
+    cursor: usize,
+}
+
+impl Player {
+    pub fn new(replay: Replay) -> Self {
+        Self { replay, cursor: 0 }
+    }
+
+    pub fn seed(&self) -> u32 {
+        self.replay.seed
+    }
+
+    /// Pops the next recorded frame, or `None` once the replay has been fully played back.
+    pub fn next_frame(&mut self) -> Option<ffi::InputState> {
+        let frame = self.replay.frames.get(self.cursor).copied();
+        if frame.is_some() {
+            self.cursor += 1;
+        }
+        frame
+    }
+}
+
+fn replays_dir() -> PathBuf {
+    PathBuf::from("save/replays")
+}
+
+/// Saves `replay` to `save/replays/{name}.json` via the same envelope/atomic-write path `save`
+/// uses for `SaveData`.
+pub fn save_replay(replay: &Replay, name: &str) -> Result<PathBuf, SaveError> {
+    let path = replays_dir().join(format!("{name}.json"));
+    save::save_to_path(&path, replay)?;
+    Ok(path)
+}
+
+pub fn load_replay(path: &Path) -> Result<Replay, SaveError> {
+    match save::load_or_default::<Replay>(path, &save::MigrationRegistry::new())? {
+        save::LoadState::Loaded(replay) => Ok(replay),
+        save::LoadState::Defaulted { data, .. } => Ok(data),
+    }
+}
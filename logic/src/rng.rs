@@ -0,0 +1,59 @@
+//! A tiny deterministic PRNG so a run's obstacle spawning can be reproduced from its seed alone.
+//!
+//! `rand::thread_rng()` pulls from OS entropy, so two runs never play out the same way even with
+//! identical input. `XorShift` is the classic 32-bit xorshift recurrence: seeded once, every call
+//! is a pure function of the current state, so replaying the same seed against the same inputs
+//! reproduces the exact same sequence of obstacle positions.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct XorShift {
+    state: u32,
+}
+
+impl XorShift {
+    /// Seeds the generator. Xorshift gets stuck at zero forever if seeded with zero, so a zero
+    /// seed is reseeded to a fixed non-zero constant instead.
+    pub fn new(seed: u32) -> Self {
+        Self {
+            state: if seed == 0 { 0x2545F491 } else { seed },
+        }
+    }
+
+    pub fn next_u32(&mut self) -> u32 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.state = x;
+        x
+    }
+
+    /// A float in `[0.0, 1.0)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// A float in `[min, max)`.
+    pub fn next_range(&mut self, min: f32, max: f32) -> f32 {
+        min + self.next_f32() * (max - min)
+    }
+
+    /// The raw recurrence state, i.e. everything needed to resume this generator exactly.
+    /// Exposed for `netplay::GameSnapshot`, which ships it instead of the whole `Game`.
+    pub fn state(&self) -> u32 {
+        self.state
+    }
+
+    /// Resumes a generator from a previously captured `state()`.
+    pub fn from_state(state: u32) -> Self {
+        Self { state }
+    }
+}
+
+impl Default for XorShift {
+    fn default() -> Self {
+        Self::new(0x2545F491)
+    }
+}
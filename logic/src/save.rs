@@ -1,4 +1,5 @@
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::{Display, Formatter};
 use std::fs;
 use std::io::Write;
@@ -26,6 +27,9 @@ pub enum SaveError {
     Io(std::io::Error),
     Serde(serde_json::Error),
     VersionMismatch { found: u32, expected: u32 },
+    /// An older save needs upgrading but no registered `Migration` starts at `from` — the
+    /// migration chain has a hole between some past schema bump and the next one.
+    MigrationGap { from: u32 },
 }
 
 impl Display for SaveError {
@@ -39,6 +43,9 @@ impl Display for SaveError {
                     "save schema version mismatch: found={found}, expected={expected}"
                 )
             }
+            SaveError::MigrationGap { from } => {
+                write!(f, "no migration registered starting from save version {from}")
+            }
         }
     }
 }
@@ -66,7 +73,58 @@ pub enum LoadState<T> {
     },
 }
 
-pub fn load_or_default<T>(path: &Path) -> Result<LoadState<T>, SaveError>
+/// One step in a save schema's upgrade path: rewrites the untyped payload from schema `FROM` to
+/// schema `TO`, so `load_or_default` can turn an old save into one `serde_json::from_value::<T>`
+/// can parse without every schema bump permanently stranding earlier saves.
+pub trait Migration {
+    const FROM: u32;
+    const TO: u32;
+    fn migrate(value: serde_json::Value) -> Result<serde_json::Value, SaveError>;
+}
+
+/// The migration steps known for a given save type, keyed by the version they upgrade from.
+/// Empty today — `SAVE_SCHEMA_VERSION` is still 1, so there's nothing yet to migrate from.
+/// The first time it bumps, register that step here with `.register::<SomeMigration>()`.
+pub struct MigrationRegistry {
+    steps: HashMap<u32, (u32, fn(serde_json::Value) -> Result<serde_json::Value, SaveError>)>,
+}
+
+impl MigrationRegistry {
+    pub fn new() -> Self {
+        Self {
+            steps: HashMap::new(),
+        }
+    }
+
+    pub fn register<M: Migration>(mut self) -> Self {
+        self.steps.insert(M::FROM, (M::TO, M::migrate));
+        self
+    }
+
+    /// Repeatedly applies the registered step starting at `version` until the payload reaches
+    /// `SAVE_SCHEMA_VERSION`, or errors with `MigrationGap` if some version in the chain has no
+    /// registered step.
+    fn apply(&self, mut value: serde_json::Value, mut version: u32) -> Result<serde_json::Value, SaveError> {
+        while version < SAVE_SCHEMA_VERSION {
+            let (to, migrate) = self
+                .steps
+                .get(&version)
+                .copied()
+                .ok_or(SaveError::MigrationGap { from: version })?;
+            value = migrate(value)?;
+            version = to;
+        }
+        Ok(value)
+    }
+}
+
+impl Default for MigrationRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn load_or_default<T>(path: &Path, migrations: &MigrationRegistry) -> Result<LoadState<T>, SaveError>
 where
     T: DeserializeOwned + Default,
 {
@@ -78,15 +136,22 @@ where
     }
 
     let raw = fs::read(path)?;
-    match serde_json::from_slice::<SaveEnvelope<T>>(&raw) {
+    match serde_json::from_slice::<SaveEnvelope<serde_json::Value>>(&raw) {
         Ok(envelope) => {
-            if envelope.save_version != SAVE_SCHEMA_VERSION {
+            if envelope.save_version > SAVE_SCHEMA_VERSION {
                 return Err(SaveError::VersionMismatch {
                     found: envelope.save_version,
                     expected: SAVE_SCHEMA_VERSION,
                 });
             }
-            Ok(LoadState::Loaded(envelope.payload))
+
+            let payload = if envelope.save_version < SAVE_SCHEMA_VERSION {
+                migrations.apply(envelope.payload, envelope.save_version)?
+            } else {
+                envelope.payload
+            };
+
+            Ok(LoadState::Loaded(serde_json::from_value(payload)?))
         }
         Err(_) => {
             let backup_path = backup_corrupt_file(path)?;
@@ -171,7 +236,7 @@ mod tests {
         let data = TestData { value: 42 };
 
         save_to_path(&path, &data).unwrap();
-        let loaded = load_or_default::<TestData>(&path).unwrap();
+        let loaded = load_or_default::<TestData>(&path, &MigrationRegistry::new()).unwrap();
 
         match loaded {
             LoadState::Loaded(v) => assert_eq!(v, data),
@@ -183,7 +248,7 @@ mod tests {
     fn load_missing_returns_default() {
         let dir = temp_dir_path();
         let path = dir.join("not_found.json");
-        let loaded = load_or_default::<TestData>(&path).unwrap();
+        let loaded = load_or_default::<TestData>(&path, &MigrationRegistry::new()).unwrap();
 
         match loaded {
             LoadState::Defaulted { data, backup_path } => {
@@ -200,7 +265,7 @@ mod tests {
         let path = dir.join("save_data.json");
         fs::write(&path, b"this is not json").unwrap();
 
-        let loaded = load_or_default::<TestData>(&path).unwrap();
+        let loaded = load_or_default::<TestData>(&path, &MigrationRegistry::new()).unwrap();
 
         match loaded {
             LoadState::Defaulted { data, backup_path } => {
@@ -212,4 +277,91 @@ mod tests {
             _ => panic!("expected defaulted state"),
         }
     }
+
+    #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+    struct MigratedData {
+        value: u32,
+        tag: String,
+    }
+
+    struct AddTagMigration;
+
+    impl Migration for AddTagMigration {
+        const FROM: u32 = 0;
+        const TO: u32 = 1;
+
+        fn migrate(mut value: serde_json::Value) -> Result<serde_json::Value, SaveError> {
+            if let serde_json::Value::Object(ref mut map) = value {
+                map.insert("tag".to_string(), serde_json::Value::String("migrated".to_string()));
+            }
+            Ok(value)
+        }
+    }
+
+    fn write_envelope(path: &Path, save_version: u32, payload: serde_json::Value) {
+        let envelope = serde_json::json!({ "save_version": save_version, "payload": payload });
+        fs::write(path, serde_json::to_vec(&envelope).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn old_version_is_migrated_before_deserializing() {
+        let dir = temp_dir_path();
+        let path = dir.join("save_data.json");
+        write_envelope(&path, 0, serde_json::json!({ "value": 7 }));
+
+        let registry = MigrationRegistry::new().register::<AddTagMigration>();
+        let loaded = load_or_default::<MigratedData>(&path, &registry).unwrap();
+
+        match loaded {
+            LoadState::Loaded(data) => assert_eq!(
+                data,
+                MigratedData {
+                    value: 7,
+                    tag: "migrated".to_string(),
+                }
+            ),
+            _ => panic!("expected loaded state"),
+        }
+    }
+
+    #[test]
+    fn missing_migration_step_errors_with_migration_gap() {
+        let dir = temp_dir_path();
+        let path = dir.join("save_data.json");
+        write_envelope(&path, 0, serde_json::json!({ "value": 7 }));
+
+        let err = load_or_default::<MigratedData>(&path, &MigrationRegistry::new()).unwrap_err();
+        assert!(matches!(err, SaveError::MigrationGap { from: 0 }));
+    }
+
+    #[test]
+    fn newer_than_binary_still_errors() {
+        let dir = temp_dir_path();
+        let path = dir.join("save_data.json");
+        write_envelope(&path, 99, serde_json::json!({ "value": 7 }));
+
+        let err = load_or_default::<TestData>(&path, &MigrationRegistry::new()).unwrap_err();
+        assert!(matches!(
+            err,
+            SaveError::VersionMismatch {
+                found: 99,
+                expected: 1
+            }
+        ));
+    }
+
+    #[test]
+    fn current_version_skips_migration_loop() {
+        let dir = temp_dir_path();
+        let path = dir.join("save_data.json");
+        write_envelope(&path, SAVE_SCHEMA_VERSION, serde_json::json!({ "value": 3 }));
+
+        // No migration registered at all — if the current-version save tried to migrate, this
+        // would fail with `MigrationGap` instead of loading directly.
+        let loaded = load_or_default::<TestData>(&path, &MigrationRegistry::new()).unwrap();
+        match loaded {
+            LoadState::Loaded(data) => assert_eq!(data, TestData { value: 3 }),
+            _ => panic!("expected loaded state"),
+        }
+    }
 }
@@ -0,0 +1,245 @@
+//! Serializable prefab/scene format layered on top of `InternalWorld`.
+//!
+//! A `SceneData` is a named collection of entities, each described by the concrete value of
+//! every component it carries. Unlike `ComponentBundle`, which is driven by compile-time tuples,
+//! `spawn_scene` reconstructs archetypes from runtime `ComponentType` values read back out of a
+//! RON/JSON document, so scenes can be authored and edited outside of Rust.
+
+use crate::{ffi, Archetype, ComponentType, Entity, InternalWorld, Material, Obstacle, Player, Sprite};
+use crate::ui::Button;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+/// The concrete value of a single component on a single entity. One variant per `ComponentType`
+/// so a `SceneData` document can carry exactly the data `InternalWorld::spawn_scene` needs to
+/// rebuild the right archetype without guessing field shapes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ComponentValue {
+    Transform(ffi::Transform),
+    Velocity(ffi::Velocity),
+    Material(Material),
+    Player(Player),
+    Obstacle(Obstacle),
+    Button(Button),
+    Physics(crate::PhysicsBody),
+    Sprite(Sprite),
+    Light(ffi::Light),
+}
+
+impl ComponentValue {
+    fn component_type(&self) -> ComponentType {
+        match self {
+            ComponentValue::Transform(_) => ComponentType::Transform,
+            ComponentValue::Velocity(_) => ComponentType::Velocity,
+            ComponentValue::Material(_) => ComponentType::Material,
+            ComponentValue::Player(_) => ComponentType::Player,
+            ComponentValue::Obstacle(_) => ComponentType::Obstacle,
+            ComponentValue::Button(_) => ComponentType::Button,
+            ComponentValue::Physics(_) => ComponentType::Physics,
+            ComponentValue::Sprite(_) => ComponentType::Sprite,
+            ComponentValue::Light(_) => ComponentType::Light,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntityData {
+    pub components: Vec<ComponentValue>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneData {
+    pub name: String,
+    pub entities: Vec<EntityData>,
+}
+
+#[derive(Debug)]
+pub enum SceneError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Ron(ron::Error),
+    RonSpanned(ron::de::SpannedError),
+}
+
+impl Display for SceneError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SceneError::Io(e) => write!(f, "I/O error: {e}"),
+            SceneError::Json(e) => write!(f, "JSON error: {e}"),
+            SceneError::Ron(e) => write!(f, "RON error: {e}"),
+            SceneError::RonSpanned(e) => write!(f, "RON error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SceneError {}
+
+impl From<std::io::Error> for SceneError {
+    fn from(value: std::io::Error) -> Self {
+        SceneError::Io(value)
+    }
+}
+
+impl From<serde_json::Error> for SceneError {
+    fn from(value: serde_json::Error) -> Self {
+        SceneError::Json(value)
+    }
+}
+
+impl From<ron::Error> for SceneError {
+    fn from(value: ron::Error) -> Self {
+        SceneError::Ron(value)
+    }
+}
+
+impl From<ron::de::SpannedError> for SceneError {
+    fn from(value: ron::de::SpannedError) -> Self {
+        SceneError::RonSpanned(value)
+    }
+}
+
+impl SceneData {
+    pub fn to_ron_pretty(&self) -> Result<String, SceneError> {
+        Ok(ron::ser::to_string_pretty(
+            self,
+            ron::ser::PrettyConfig::default(),
+        )?)
+    }
+
+    pub fn from_ron(text: &str) -> Result<Self, SceneError> {
+        Ok(ron::de::from_str(text)?)
+    }
+
+    /// Loads a scene from `path`, dispatching on extension: `.ron` parses as RON, anything else
+    /// (including `.json`) parses as JSON.
+    pub fn load(path: &Path) -> Result<Self, SceneError> {
+        let text = fs::read_to_string(path)?;
+        let is_ron = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| ext.eq_ignore_ascii_case("ron"))
+            .unwrap_or(false);
+
+        if is_ron {
+            Self::from_ron(&text)
+        } else {
+            Ok(serde_json::from_str(&text)?)
+        }
+    }
+
+    /// Walks `world`'s archetypes and downcasts each storage vec back into `ComponentValue`s,
+    /// giving a round-trippable snapshot of a live scene. `Archetype::storage` is `#[serde(skip)]`
+    /// so this is the only way to turn a running world back into a serializable document.
+    pub fn from_world(name: &str, world: &InternalWorld) -> Self {
+        let mut entities = Vec::new();
+
+        for archetype in &world.archetypes {
+            for row in 0..archetype.entity_count {
+                let mut components = Vec::new();
+                if let Some(v) = downcast_row::<ffi::Transform>(archetype, ComponentType::Transform, row) {
+                    components.push(ComponentValue::Transform(v));
+                }
+                if let Some(v) = downcast_row::<ffi::Velocity>(archetype, ComponentType::Velocity, row) {
+                    components.push(ComponentValue::Velocity(v));
+                }
+                if let Some(v) = downcast_row::<Material>(archetype, ComponentType::Material, row) {
+                    components.push(ComponentValue::Material(v));
+                }
+                if let Some(v) = downcast_row::<Player>(archetype, ComponentType::Player, row) {
+                    components.push(ComponentValue::Player(v));
+                }
+                if let Some(v) = downcast_row::<Obstacle>(archetype, ComponentType::Obstacle, row) {
+                    components.push(ComponentValue::Obstacle(v));
+                }
+                if let Some(v) = downcast_row::<Button>(archetype, ComponentType::Button, row) {
+                    components.push(ComponentValue::Button(v));
+                }
+                if let Some(v) =
+                    downcast_row::<crate::PhysicsBody>(archetype, ComponentType::Physics, row)
+                {
+                    components.push(ComponentValue::Physics(v));
+                }
+                if let Some(v) = downcast_row::<Sprite>(archetype, ComponentType::Sprite, row) {
+                    components.push(ComponentValue::Sprite(v));
+                }
+                if let Some(v) = downcast_row::<ffi::Light>(archetype, ComponentType::Light, row) {
+                    components.push(ComponentValue::Light(v));
+                }
+                entities.push(EntityData { components });
+            }
+        }
+
+        SceneData {
+            name: name.to_string(),
+            entities,
+        }
+    }
+}
+
+pub(crate) fn downcast_row<T: Clone + 'static>(
+    archetype: &Archetype,
+    component_type: ComponentType,
+    row: usize,
+) -> Option<T> {
+    archetype
+        .storage
+        .get(&component_type)?
+        .downcast_ref::<Vec<T>>()?
+        .get(row)
+        .cloned()
+}
+
+impl InternalWorld {
+    /// Spawns every entity described by `scene`, reconstructing archetypes from the runtime
+    /// `ComponentType` set each entity carries (mirroring what `ComponentBundle::push_to_storage`
+    /// does for compile-time tuples) and returns the newly created entities in document order.
+    pub fn spawn_scene(&mut self, scene: &SceneData) -> Vec<Entity> {
+        let mut spawned = Vec::with_capacity(scene.entities.len());
+
+        for entity_data in &scene.entities {
+            let types: std::collections::HashSet<ComponentType> = entity_data
+                .components
+                .iter()
+                .map(ComponentValue::component_type)
+                .collect();
+            let archetype_idx = self.get_or_create_archetype(types);
+            let archetype = &mut self.archetypes[archetype_idx];
+
+            for component in &entity_data.components {
+                match component {
+                    ComponentValue::Transform(v) => push_value(archetype, ComponentType::Transform, *v),
+                    ComponentValue::Velocity(v) => push_value(archetype, ComponentType::Velocity, *v),
+                    ComponentValue::Material(v) => push_value(archetype, ComponentType::Material, *v),
+                    ComponentValue::Player(v) => push_value(archetype, ComponentType::Player, *v),
+                    ComponentValue::Obstacle(v) => push_value(archetype, ComponentType::Obstacle, *v),
+                    ComponentValue::Button(v) => push_value(archetype, ComponentType::Button, v.clone()),
+                    ComponentValue::Physics(v) => push_value(archetype, ComponentType::Physics, *v),
+                    ComponentValue::Sprite(v) => push_value(archetype, ComponentType::Sprite, *v),
+                    ComponentValue::Light(v) => push_value(archetype, ComponentType::Light, *v),
+                }
+            }
+
+            let entity_idx_in_archetype = archetype.entity_count;
+            archetype.entity_count += 1;
+            let entity = Entity(self.next_entity);
+            self.next_entity += 1;
+            self.entities
+                .insert(entity, (archetype_idx, entity_idx_in_archetype));
+            spawned.push(entity);
+        }
+
+        spawned
+    }
+}
+
+fn push_value<T: 'static>(archetype: &mut Archetype, component_type: ComponentType, value: T) {
+    archetype
+        .storage
+        .entry(component_type)
+        .or_insert_with(|| Box::new(Vec::<T>::new()))
+        .downcast_mut::<Vec<T>>()
+        .expect("archetype storage type mismatch for component")
+        .push(value);
+}
@@ -0,0 +1,249 @@
+//! A small TSC-style ("text script") bytecode VM for cutscenes, intros, and tutorials, in the
+//! spirit of doukutsu-rs's scripted-event system. A `ScriptVm` owns a program counter, the parsed
+//! command list, a wait timer, and a set of named flags; `GameState::Cutscene` ticks it once per
+//! `update()` frame, executing commands until it hits a `WAIT` or `END`.
+//!
+//! Scripts are plain text, one command per line:
+//! ```text
+//! TEXT "Welcome to MIYABI!"
+//! WAIT 90
+//! SET_FLAG intro_seen
+//! SPAWN_OBSTACLE 3
+//! intro_done:
+//! END
+//! ```
+//! A bare `name:` line declares a label that `JUMP_IF <flag> <label>` can target; `#` starts a
+//! comment; blank lines are ignored.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Command {
+    Text(String),
+    Wait(u32),
+    SpawnObstacle(u32),
+    SetFlag(String),
+    JumpIf { flag: String, label: String },
+    End,
+}
+
+#[derive(Debug)]
+pub enum ScriptError {
+    Io(std::io::Error),
+    UnknownOpcode(String),
+    MissingArgument(String),
+    InvalidArgument(String),
+    UnterminatedString(String),
+}
+
+impl Display for ScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScriptError::Io(e) => write!(f, "I/O error: {e}"),
+            ScriptError::UnknownOpcode(op) => write!(f, "unknown opcode: {op}"),
+            ScriptError::MissingArgument(op) => write!(f, "missing argument for {op}"),
+            ScriptError::InvalidArgument(op) => write!(f, "invalid argument for {op}"),
+            ScriptError::UnterminatedString(line) => {
+                write!(f, "unterminated string literal: {line}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+impl From<std::io::Error> for ScriptError {
+    fn from(value: std::io::Error) -> Self {
+        ScriptError::Io(value)
+    }
+}
+
+/// A parsed script: its commands plus a label -> command-index table for `JUMP_IF`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Script {
+    commands: Vec<Command>,
+    labels: HashMap<String, usize>,
+}
+
+impl Script {
+    pub fn load(path: &Path) -> Result<Self, ScriptError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Parses a script from its text form, see the module doc for the format.
+    pub fn parse(source: &str) -> Result<Self, ScriptError> {
+        let mut commands = Vec::new();
+        let mut labels = HashMap::new();
+
+        for raw_line in source.lines() {
+            let line = raw_line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(label) = line.strip_suffix(':') {
+                labels.insert(label.trim().to_string(), commands.len());
+                continue;
+            }
+
+            commands.push(parse_command(line)?);
+        }
+
+        Ok(Script { commands, labels })
+    }
+}
+
+fn parse_command(line: &str) -> Result<Command, ScriptError> {
+    let (opcode, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let rest = rest.trim();
+
+    match opcode {
+        "TEXT" => {
+            let text = rest
+                .strip_prefix('"')
+                .and_then(|s| s.strip_suffix('"'))
+                .ok_or_else(|| ScriptError::UnterminatedString(line.to_string()))?;
+            Ok(Command::Text(text.to_string()))
+        }
+        "WAIT" => rest
+            .parse::<u32>()
+            .map(Command::Wait)
+            .map_err(|_| ScriptError::InvalidArgument(line.to_string())),
+        "SPAWN_OBSTACLE" => rest
+            .parse::<u32>()
+            .map(Command::SpawnObstacle)
+            .map_err(|_| ScriptError::InvalidArgument(line.to_string())),
+        "SET_FLAG" => {
+            if rest.is_empty() {
+                return Err(ScriptError::MissingArgument(line.to_string()));
+            }
+            Ok(Command::SetFlag(rest.to_string()))
+        }
+        "JUMP_IF" => {
+            let mut parts = rest.split_whitespace();
+            let flag = parts
+                .next()
+                .ok_or_else(|| ScriptError::MissingArgument(line.to_string()))?;
+            let label = parts
+                .next()
+                .ok_or_else(|| ScriptError::MissingArgument(line.to_string()))?;
+            Ok(Command::JumpIf {
+                flag: flag.to_string(),
+                label: label.to_string(),
+            })
+        }
+        "END" => Ok(Command::End),
+        _ => Err(ScriptError::UnknownOpcode(opcode.to_string())),
+    }
+}
+
+/// Upper bound on commands dispatched by a single `tick()` call. A well-formed script always
+/// bottoms out in a `WAIT` or `END` within a handful of commands; a content bug (e.g. a `JUMP_IF`
+/// loop whose flag is set by a prior `SET_FLAG` and never cleared, with no intervening `WAIT`)
+/// would otherwise spin `tick`'s dispatch loop forever and hang the frame it's called from.
+const MAX_STEPS_PER_TICK: u32 = 10_000;
+
+/// Runs a `Script`, advancing it once per `tick`. Decoupled from `Game` via the two
+/// callbacks `tick` takes, so the VM itself has no dependency on the ECS or renderer.
+#[derive(Debug)]
+pub struct ScriptVm {
+    script: Script,
+    pc: usize,
+    wait_timer: u32,
+    flags: HashSet<String>,
+    finished: bool,
+}
+
+impl ScriptVm {
+    /// Starts a fresh run of `script`, seeded with `flags` already set (e.g. flags persisted from
+    /// earlier cutscenes), so a `JUMP_IF` can skip content the player has already unlocked.
+    pub fn new(script: Script, flags: HashSet<String>) -> Self {
+        Self {
+            script,
+            pc: 0,
+            wait_timer: 0,
+            flags,
+            finished: false,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    pub fn flags(&self) -> &HashSet<String> {
+        &self.flags
+    }
+
+    /// Advances the VM by one frame: decrements an active wait, or otherwise executes commands
+    /// until it hits a `WAIT` or `END`. `on_text` is called once per `TEXT`, `on_spawn_obstacle`
+    /// once per `SPAWN_OBSTACLE <n>` with `n`.
+    pub fn tick(&mut self, mut on_text: impl FnMut(&str), mut on_spawn_obstacle: impl FnMut(u32)) {
+        if self.finished {
+            return;
+        }
+        if self.wait_timer > 0 {
+            self.wait_timer -= 1;
+            return;
+        }
+
+        for _ in 0..MAX_STEPS_PER_TICK {
+            let Some(command) = self.script.commands.get(self.pc).cloned() else {
+                self.finished = true;
+                return;
+            };
+            self.pc += 1;
+
+            match command {
+                Command::Text(text) => on_text(&text),
+                Command::Wait(frames) => {
+                    self.wait_timer = frames;
+                    return;
+                }
+                Command::SpawnObstacle(count) => on_spawn_obstacle(count),
+                Command::SetFlag(id) => {
+                    self.flags.insert(id);
+                }
+                Command::JumpIf { flag, label } => {
+                    if self.flags.contains(&flag) {
+                        if let Some(&target) = self.script.labels.get(&label) {
+                            self.pc = target;
+                        }
+                    }
+                }
+                Command::End => {
+                    self.finished = true;
+                    return;
+                }
+            }
+        }
+
+        eprintln!(
+            "[script] aborting after {MAX_STEPS_PER_TICK} commands in one tick (likely a \
+             JUMP_IF loop with no intervening WAIT); stopping this script"
+        );
+        self.finished = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tick_stops_instead_of_hanging_on_a_wait_free_jump_loop() {
+        let script = Script::parse(
+            "loop:\nSET_FLAG x\nJUMP_IF x loop\n",
+        )
+        .unwrap();
+        let mut vm = ScriptVm::new(script, HashSet::new());
+
+        vm.tick(|_| {}, |_| {});
+
+        assert!(vm.is_finished());
+    }
+}
@@ -0,0 +1,84 @@
+//! PNG-authored stage/level loading: an alternative to `spawn_obstacle`'s purely random spawner
+//! that lets a level be hand-drawn as an image. Each pixel column is a spawn lane and each row is
+//! a moment in time as the stage "scrolls" past; a pixel's color encodes what to emit there: the
+//! red channel picks an obstacle speed tier, the green channel marks a pickup, and black means
+//! nothing spawns. `update_in_game` drains the resulting `SpawnEvent`s in order and falls back to
+//! the random spawner once they run out.
+
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
+/// Seconds of gameplay time each image row represents, i.e. how fast the stage "scrolls".
+const ROW_TIME_SEC: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpawnKind {
+    Obstacle,
+    /// Parsed but not yet wired to a gameplay component — reserved for a future pickup/scoring
+    /// system. `update_in_game` currently skips these rather than spawning anything for them.
+    Pickup,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpawnEvent {
+    pub time_sec: f32,
+    pub x: f32,
+    pub kind: SpawnKind,
+    pub speed: f32,
+}
+
+#[derive(Debug)]
+pub enum StageError {
+    Image(image::ImageError),
+}
+
+impl Display for StageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StageError::Image(e) => write!(f, "image error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for StageError {}
+
+impl From<image::ImageError> for StageError {
+    fn from(value: image::ImageError) -> Self {
+        StageError::Image(value)
+    }
+}
+
+/// Parses `path` into a time-ordered list of `SpawnEvent`s, mapping pixel columns to lanes across
+/// `screen_width` (matching the `20.0..=screen_width - 20.0` range `spawn_obstacle` spawns into).
+pub fn load_stage(path: &Path, screen_width: f32, base_speed: f32) -> Result<Vec<SpawnEvent>, StageError> {
+    let image = image::open(path)?.into_rgba8();
+    let (width, height) = image.dimensions();
+    let mut events = Vec::new();
+
+    for row in 0..height {
+        let time_sec = row as f32 * ROW_TIME_SEC;
+        for col in 0..width {
+            let pixel = image.get_pixel(col, row).0;
+            let (r, g, b) = (pixel[0], pixel[1], pixel[2]);
+            if r == 0 && g == 0 && b == 0 {
+                continue;
+            }
+
+            let x = lane_to_x(col, width, screen_width);
+            if g > 0 {
+                events.push(SpawnEvent { time_sec, x, kind: SpawnKind::Pickup, speed: 0.0 });
+            } else if r > 0 {
+                let speed = base_speed + (r as f32 / 255.0) * 200.0;
+                events.push(SpawnEvent { time_sec, x, kind: SpawnKind::Obstacle, speed });
+            }
+        }
+    }
+
+    events.sort_by(|a, b| a.time_sec.partial_cmp(&b.time_sec).unwrap());
+    Ok(events)
+}
+
+fn lane_to_x(col: u32, width: u32, screen_width: f32) -> f32 {
+    let lane_center = (col as f32 + 0.5) / width.max(1) as f32;
+    20.0 + lane_center * (screen_width - 40.0)
+}
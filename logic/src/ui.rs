@@ -1,8 +1,8 @@
 // logic/src/ui.rs
 use serde::{Deserialize, Serialize};
 
-use crate::ffi::{Vec2, Vec4};
-use crate::{Game, GameState};
+use crate::ffi::{RectCommand, Vec2, Vec4};
+use crate::{ComponentType, Game, GameState};
 
 // 1. Define the Button Component
 // =============================
@@ -38,6 +38,25 @@ pub enum ButtonAction {
     SeVolumeDown,
     SeVolumeUp,
     ToggleFullscreen,
+    CycleLanguage,
+    SaveReplay,
+    SelectSlot(usize),
+    DeleteSlot(usize),
+    /// A `(host_fn arg...)` call evaluated by `ui_script::eval`, for buttons wired up from a
+    /// serialized menu file rather than one of the hard-coded variants above. Only constructible
+    /// and only handled when the `scripting` feature is enabled.
+    #[cfg(feature = "scripting")]
+    Script(String),
+}
+
+/// A button's per-frame interaction state, derived fresh from `mouse_pos`/`mouse_clicked` every
+/// `ui_system` call rather than stored on the component — an immediate-mode widget has no need to
+/// remember yesterday's hover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WidgetState {
+    Normal,
+    Hover,
+    Pressed,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -45,29 +64,105 @@ pub struct Button {
     pub rect: Rect,
     pub text: String,
     pub action: ButtonAction,
-    // pub font_size: f32,
-    // pub color: Vec4,
-    // pub hover_color: Vec4,
-    // pub pressed_color: Vec4,
+    pub font_size: f32,
+    pub color: Vec4,
+    pub hover_color: Vec4,
+    pub pressed_color: Vec4,
+    /// BMFont handle from `AssetServer::load_font`, or `0` to fall back to `ui_system`'s rough
+    /// per-character width estimate.
+    pub font_handle: u32,
+}
+
+impl Default for Button {
+    fn default() -> Self {
+        Self {
+            rect: Rect {
+                x: 0.0,
+                y: 0.0,
+                width: 0.0,
+                height: 0.0,
+            },
+            text: String::new(),
+            action: ButtonAction::BackToTitle,
+            font_size: 24.0,
+            color: Vec4 { x: 0.2, y: 0.22, z: 0.28, w: 1.0 },
+            hover_color: Vec4 { x: 0.3, y: 0.33, z: 0.42, w: 1.0 },
+            pressed_color: Vec4 { x: 0.12, y: 0.13, z: 0.18, w: 1.0 },
+            font_handle: 0,
+        }
+    }
+}
+
+/// Measures `text`'s pixel width/height using `font_handle`'s loaded BMFont metrics, or the old
+/// `chars().count() * 6.0` guess (now counting characters, not bytes, so multibyte text isn't
+/// over-counted) if no font is loaded under that handle.
+fn measure_label(game: &Game, font_handle: u32, text: &str) -> (f32, f32) {
+    match game.asset_server.font_metrics(font_handle) {
+        Some(metrics) => metrics.measure(text),
+        None => (text.chars().count() as f32 * 6.0, 16.0),
+    }
+}
+
+/// What a `Slider`'s drag feeds into once it lands, mirroring `ButtonAction`'s closed set of
+/// hard-coded targets: one variant per `Game` setter `ui_system` can call with the new value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SliderAction {
+    MasterVolume,
+    BgmVolume,
+    SeVolume,
+}
+
+/// A draggable `min..=max` control: `rect` is the track, the knob is a fixed-width marker drawn
+/// at `value`'s position along it. Dragging recomputes `value` from the mouse's position inside
+/// `rect` every frame (absolute positioning, not a delta), so there's no separate "drag started"
+/// state to track between frames.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Slider {
+    pub rect: Rect,
+    pub min: f32,
+    pub max: f32,
+    pub value: f32,
+    pub step: f32,
+    pub action: SliderAction,
 }
 
-// We need a way to register this as a component.
-// We'll add a new ComponentType for it.
-use crate::Component;
-use crate::ComponentType;
+impl Slider {
+    /// Fraction of the track `value` sits at, in `0.0..=1.0`.
+    fn fraction(&self) -> f32 {
+        if self.max <= self.min {
+            return 0.0;
+        }
+        ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
 
-impl Component for Button {
-    const COMPONENT_TYPE: ComponentType = ComponentType::Button;
+    /// Resolves `point`'s x position within `rect` to a stepped, clamped value, for a click or
+    /// drag anywhere along the track.
+    fn value_at(&self, point_x: f32) -> f32 {
+        if self.rect.width <= 0.0 {
+            return self.value;
+        }
+        let t = ((point_x - self.rect.x) / self.rect.width).clamp(0.0, 1.0);
+        let raw = self.min + t * (self.max - self.min);
+        let stepped = if self.step > 0.0 {
+            (raw / self.step).round() * self.step
+        } else {
+            raw
+        };
+        stepped.clamp(self.min, self.max)
+    }
 }
 
 // 2. UI System Logic
 // ==================
 
-/// The UI system handles button interactions and drawing.
+/// The UI system handles button/slider interactions and drawing. Both widgets are rebuilt every
+/// call: `rect_commands` is cleared up front and repopulated from the live `Button`/`Slider`
+/// components, the same immediate-mode contract `text_commands` already follows for menu labels.
 pub fn ui_system(game: &mut Game) {
     let mouse_pos = game.input_state.mouse_pos;
     let mouse_clicked = game.input_state.mouse_clicked;
     let mut queued_action: Option<ButtonAction> = None;
+    game.rect_commands.clear();
 
     // Find archetypes with a Button component
     for archetype in &game.world.archetypes {
@@ -80,36 +175,99 @@ pub fn ui_system(game: &mut Game) {
                 .unwrap();
 
             for button in buttons.iter() {
+                let hovering = button.rect.contains(mouse_pos);
                 // --- Interaction Logic ---
-                if mouse_clicked && queued_action.is_none() && button.rect.contains(mouse_pos) {
+                if mouse_clicked && queued_action.is_none() && hovering {
                     queued_action = Some(button.action.clone());
                 }
 
+                let state = match (hovering, mouse_clicked) {
+                    (true, true) => WidgetState::Pressed,
+                    (true, false) => WidgetState::Hover,
+                    (false, _) => WidgetState::Normal,
+                };
+                let fill_color = match state {
+                    WidgetState::Normal => button.color,
+                    WidgetState::Hover => button.hover_color,
+                    WidgetState::Pressed => button.pressed_color,
+                };
+
                 // --- Drawing Logic ---
-                // For now, just draw the text. A more complex system would also draw the button's rectangle.
+                game.rect_commands.push(RectCommand {
+                    position: Vec2 {
+                        x: button.rect.x,
+                        y: button.rect.y,
+                    },
+                    size: Vec2 {
+                        x: button.rect.width,
+                        y: button.rect.height,
+                    },
+                    color: fill_color,
+                });
+                let (text_width, text_height) = measure_label(game, button.font_handle, &button.text);
                 game.text_commands.push(crate::ffi::TextCommand {
                     text: button.text.clone(),
-                    // Center the text roughly
                     position: Vec2 {
-                        x: button.rect.x + (button.rect.width / 2.0)
-                            - (button.text.len() as f32 * 6.0), // Estimate
-                        y: button.rect.y + (button.rect.height / 2.0) - 8.0, // Estimate
+                        x: button.rect.x + (button.rect.width - text_width) / 2.0,
+                        y: button.rect.y + (button.rect.height - text_height) / 2.0,
                     },
-                    font_size: 24.0,
+                    font_size: button.font_size,
                     color: Vec4 {
                         x: 1.0,
                         y: 1.0,
                         z: 1.0,
                         w: 1.0,
                     },
+                    alignment: crate::ffi::TextAlign::Center,
+                    font_handle: button.font_handle,
                 });
             }
         }
     }
 
+    // Sliders work the same way but dragging also writes the new value straight back into the
+    // live component, since (unlike buttons) a slider's continuous state has to persist across
+    // frames without the screen being fully re-spawned.
+    let mut slider_update: Option<(SliderAction, f32)> = None;
+    for (_, (slider,)) in game.world.query_mut::<(Slider,)>() {
+        let hovering = slider.rect.contains(mouse_pos);
+        if mouse_clicked && hovering {
+            slider.value = slider.value_at(mouse_pos.x);
+            slider_update = Some((slider.action, slider.value));
+        }
+
+        let track_color = Vec4 { x: 0.15, y: 0.16, z: 0.2, w: 1.0 };
+        game.rect_commands.push(RectCommand {
+            position: Vec2 { x: slider.rect.x, y: slider.rect.y },
+            size: Vec2 { x: slider.rect.width, y: slider.rect.height },
+            color: track_color,
+        });
+
+        let knob_width = 16.0_f32.min(slider.rect.width);
+        let knob_x = slider.rect.x + slider.fraction() * (slider.rect.width - knob_width);
+        let knob_color = if hovering && mouse_clicked {
+            Vec4 { x: 1.0, y: 0.9, z: 0.4, w: 1.0 }
+        } else {
+            Vec4 { x: 0.8, y: 0.82, z: 0.9, w: 1.0 }
+        };
+        game.rect_commands.push(RectCommand {
+            position: Vec2 { x: knob_x, y: slider.rect.y },
+            size: Vec2 { x: knob_width, y: slider.rect.height },
+            color: knob_color,
+        });
+    }
+
+    if let Some((action, value)) = slider_update {
+        match action {
+            SliderAction::MasterVolume => game.set_master_volume(value),
+            SliderAction::BgmVolume => game.set_bgm_volume(value),
+            SliderAction::SeVolume => game.set_se_volume(value),
+        }
+    }
+
     // If an action was queued, perform it now.
     if let Some(action) = queued_action {
-        crate::ffi::play_sound("assets/test_sound.wav");
+        game.play_sound("assets/test_sound.wav");
         match action {
             ButtonAction::StartGame => {
                 game.start_new_run();
@@ -145,6 +303,24 @@ pub fn ui_system(game: &mut Game) {
             ButtonAction::ToggleFullscreen => {
                 game.toggle_fullscreen_setting();
             }
+            ButtonAction::CycleLanguage => {
+                game.cycle_language();
+            }
+            ButtonAction::SaveReplay => {
+                game.save_current_replay();
+            }
+            ButtonAction::SelectSlot(slot) => {
+                game.select_slot(slot);
+            }
+            ButtonAction::DeleteSlot(slot) => {
+                game.delete_slot(slot);
+            }
+            #[cfg(feature = "scripting")]
+            ButtonAction::Script(source) => {
+                if let Err(err) = crate::ui_script::eval(&source, game) {
+                    eprintln!("[ui_script] {err}");
+                }
+            }
         }
     }
 }
@@ -0,0 +1,147 @@
+//! Minimal Lisp-style host-function calls for `ui::ButtonAction::Script`, gated behind the same
+//! `scripting` feature as `gameplay_script`. Rather than a general-purpose interpreter, a button's
+//! script is a single s-expression call — `(host_fn arg...)` — dispatched directly onto the
+//! matching `Game` mutator, so a menu file can wire a button to existing gameplay behavior without
+//! recompiling the crate. Unknown functions or malformed calls report an error instead of
+//! silently doing nothing, so a typo in a menu file is easy to spot.
+//!
+//! ```text
+//! (start_new_run)
+//! (adjust_master_volume -0.05)
+//! (select_slot 2)
+//! ```
+
+use crate::{Game, GameState};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug)]
+pub enum UiScriptError {
+    Syntax(String),
+    UnknownFunction(String),
+    ArityMismatch {
+        function: String,
+        expected: usize,
+        found: usize,
+    },
+    InvalidArgument(String),
+}
+
+impl Display for UiScriptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UiScriptError::Syntax(source) => write!(f, "malformed script call: {source}"),
+            UiScriptError::UnknownFunction(name) => write!(f, "unknown host function: {name}"),
+            UiScriptError::ArityMismatch {
+                function,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{function} expects {expected} argument(s), got {found}"
+            ),
+            UiScriptError::InvalidArgument(arg) => write!(f, "invalid argument: {arg}"),
+        }
+    }
+}
+
+impl std::error::Error for UiScriptError {}
+
+/// Evaluates a single `(host_fn arg...)` call against `game`. See the module doc for the grammar
+/// and `dispatch` for the list of callable host functions.
+pub fn eval(source: &str, game: &mut Game) -> Result<(), UiScriptError> {
+    let (name, args) = parse_call(source)?;
+    dispatch(&name, &args, game)
+}
+
+fn parse_call(source: &str) -> Result<(String, Vec<String>), UiScriptError> {
+    let trimmed = source.trim();
+    let inner = trimmed
+        .strip_prefix('(')
+        .and_then(|s| s.strip_suffix(')'))
+        .ok_or_else(|| UiScriptError::Syntax(trimmed.to_string()))?;
+
+    let mut parts = inner.split_whitespace();
+    let name = parts
+        .next()
+        .ok_or_else(|| UiScriptError::Syntax(trimmed.to_string()))?
+        .to_string();
+    let args = parts.map(|s| s.to_string()).collect();
+    Ok((name, args))
+}
+
+fn arg_f32(function: &str, args: &[String], index: usize) -> Result<f32, UiScriptError> {
+    let raw = args
+        .get(index)
+        .ok_or_else(|| UiScriptError::ArityMismatch {
+            function: function.to_string(),
+            expected: index + 1,
+            found: args.len(),
+        })?;
+    raw.parse::<f32>()
+        .map_err(|_| UiScriptError::InvalidArgument(raw.clone()))
+}
+
+fn arg_usize(function: &str, args: &[String], index: usize) -> Result<usize, UiScriptError> {
+    let raw = args
+        .get(index)
+        .ok_or_else(|| UiScriptError::ArityMismatch {
+            function: function.to_string(),
+            expected: index + 1,
+            found: args.len(),
+        })?;
+    raw.parse::<usize>()
+        .map_err(|_| UiScriptError::InvalidArgument(raw.clone()))
+}
+
+/// The host functions a `ButtonAction::Script` can call, each wired straight onto the `Game`
+/// mutator the equivalent hard-coded `ButtonAction` variant uses in `ui::ui_system`.
+fn dispatch(name: &str, args: &[String], game: &mut Game) -> Result<(), UiScriptError> {
+    match name {
+        "start_new_run" => {
+            game.start_new_run();
+            Ok(())
+        }
+        "resume_game" => {
+            game.clear_menu_buttons();
+            game.current_state = GameState::InGame;
+            Ok(())
+        }
+        "back_to_title" => {
+            game.setup_title_screen();
+            Ok(())
+        }
+        "adjust_master_volume" => {
+            game.adjust_master_volume(arg_f32(name, args, 0)?);
+            Ok(())
+        }
+        "adjust_bgm_volume" => {
+            game.adjust_bgm_volume(arg_f32(name, args, 0)?);
+            Ok(())
+        }
+        "adjust_se_volume" => {
+            game.adjust_se_volume(arg_f32(name, args, 0)?);
+            Ok(())
+        }
+        "toggle_fullscreen" => {
+            game.toggle_fullscreen_setting();
+            Ok(())
+        }
+        "cycle_language" => {
+            game.cycle_language();
+            Ok(())
+        }
+        "save_replay" => {
+            game.save_current_replay();
+            Ok(())
+        }
+        "select_slot" => {
+            game.select_slot(arg_usize(name, args, 0)?);
+            Ok(())
+        }
+        "delete_slot" => {
+            game.delete_slot(arg_usize(name, args, 0)?);
+            Ok(())
+        }
+        _ => Err(UiScriptError::UnknownFunction(name.to_string())),
+    }
+}